@@ -1,34 +1,64 @@
-use crate::event_stream::EventStream;
-use crate::rest::events::{Event, EventType};
-use crate::rest::system;
+use crate::error_stream::ErrorStream;
+use crate::event_stream::{EventStream, Source};
+use crate::page_stream::PageStream;
+use crate::rest::events::{
+    Event, EventData, EventType, FolderError, FolderState, FolderSummaryData,
+};
+use crate::rest::{
+    cluster, config, db, debug, folder, metrics, noauth, stats, svc, system, DeviceID,
+};
 use crate::routes::*;
-use crate::utils::QueryChars;
+use crate::tree_stream::TreeStream;
+use crate::utils::{percent_encode, QueryChars};
 use crate::Fallible;
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use bytes::buf::BufExt as _;
 use bytes::Buf;
+use chrono::{DateTime, FixedOffset};
 use http::header::HeaderValue;
 use http::request::Request;
 use http::uri::{Authority, Parts as UriParts, PathAndQuery, Scheme, Uri};
+use hyper::body::HttpBody as _;
 use hyper::client::HttpConnector;
-use hyper::{Client as HyperClient, Method};
+use hyper::{Body, Client as HyperClient, Method, Response};
 use serde::de::DeserializeOwned as Deserialize;
+use std::collections::HashMap;
+use std::io::Read;
+use std::num::NonZeroU64;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 static API_HEADER_KEY: &str = "X-API-Key";
 static API_DEFAULT_AUTHORITY: &str = "127.0.0.1:8384";
 static EMPTY_EVENT_SUBSCRIPTION: Vec<EventType> = Vec::new();
 
+/// The page size used when a helper fetches all pages at once.
+fn perpage() -> NonZeroU64 {
+    NonZeroU64::new(1000).unwrap()
+}
+
+fn debug_path(dump: debug::download::Dump) -> &'static str {
+    use debug::download::Dump;
+    match dump {
+        Dump::CpuProfile => DEBUG_CPU_PROF_PATH,
+        Dump::HeapProfile => DEBUG_HEAP_PROF_PATH,
+        Dump::SupportBundle => DEBUG_SUPPORT_PATH,
+    }
+}
+
+#[derive(Clone)]
 pub struct Client {
     client: HyperClient<HttpConnector>,
     authority: Authority,
-    api_key: String,
+    api_key: Option<String>,
 }
 
 impl Client {
     pub fn new(api_key: impl Into<String>) -> Self {
         Self {
             client: HyperClient::new(),
-            api_key: api_key.into(),
+            api_key: Some(api_key.into()),
             authority: Authority::from_static(API_DEFAULT_AUTHORITY),
         }
     }
@@ -39,7 +69,7 @@ impl Client {
     ) -> Self {
         Self {
             client,
-            api_key: api_key.into(),
+            api_key: Some(api_key.into()),
             authority: Authority::from_static(API_DEFAULT_AUTHORITY),
         }
     }
@@ -47,7 +77,7 @@ impl Client {
     pub fn new_with_authority(api_key: impl Into<String>, authority: Authority) -> Self {
         Self {
             client: HyperClient::new(),
-            api_key: api_key.into(),
+            api_key: Some(api_key.into()),
             authority,
         }
     }
@@ -59,41 +89,129 @@ impl Client {
     ) -> Self {
         Self {
             client,
-            api_key: api_key.into(),
+            api_key: Some(api_key.into()),
             authority,
         }
     }
 
-    pub(crate) async fn request<D: Deserialize, T: AsRef<[u8]> + 'static>(
+    /// Creates a client without an API key, only the `noauth` endpoints
+    /// like [`Client::health`] will succeed.
+    pub fn new_unauthenticated() -> Self {
+        Self {
+            client: HyperClient::new(),
+            api_key: None,
+            authority: Authority::from_static(API_DEFAULT_AUTHORITY),
+        }
+    }
+
+    pub fn new_unauthenticated_with_authority(authority: Authority) -> Self {
+        Self {
+            client: HyperClient::new(),
+            api_key: None,
+            authority,
+        }
+    }
+
+    async fn send<T: AsRef<[u8]> + 'static>(
         &self,
         method: Method,
         path_and_query: T,
-    ) -> Fallible<D> {
+        body: Body,
+    ) -> Fallible<Response<Body>> {
         let mut uri_parts = UriParts::default();
         uri_parts.authority = Some(self.authority.clone());
         uri_parts.scheme = Some(Scheme::HTTP);
         uri_parts.path_and_query = Some(PathAndQuery::from_maybe_shared(path_and_query)?);
         let uri = Uri::from_parts(uri_parts)?;
-        let mut request = Request::new(Default::default());
+        let mut request = Request::new(body);
         *request.uri_mut() = uri;
         *request.method_mut() = method;
-        request
-            .headers_mut()
-            .insert(API_HEADER_KEY, HeaderValue::from_str(&self.api_key)?);
+        if let Some(api_key) = &self.api_key {
+            request
+                .headers_mut()
+                .insert(API_HEADER_KEY, HeaderValue::from_str(api_key)?);
+        }
         let resp = self.client.request(request).await?;
         let status_code = resp.status().as_u16();
-        let body = hyper::body::aggregate(resp).await?;
-        if status_code < 200 || status_code > 299 {
+        if !(200..=299).contains(&status_code) {
+            let body = hyper::body::aggregate(resp).await?;
             bail!(
                 "got http status code '{}' with following msg:\n {}",
                 status_code,
                 String::from_utf8_lossy(body.bytes())
             )
         } else {
-            Ok(serde_json::from_reader(body.reader())?)
+            Ok(resp)
         }
     }
 
+    async fn raw_request<T: AsRef<[u8]> + 'static>(
+        &self,
+        method: Method,
+        path_and_query: T,
+        body: Body,
+    ) -> Fallible<impl Buf> {
+        let resp = self.send(method, path_and_query, body).await?;
+        Ok(hyper::body::aggregate(resp).await?)
+    }
+
+    /// Streams the body of a GET request into `writer` without buffering it.
+    async fn download<T: AsRef<[u8]> + 'static>(
+        &self,
+        path_and_query: T,
+        writer: &mut (impl AsyncWrite + Unpin),
+    ) -> Fallible<debug::download::Download> {
+        let resp = self
+            .send(Method::GET, path_and_query, Body::empty())
+            .await?;
+        Self::write_body(resp, writer).await
+    }
+
+    async fn write_body(
+        resp: Response<Body>,
+        writer: &mut (impl AsyncWrite + Unpin),
+    ) -> Fallible<debug::download::Download> {
+        let download = debug::download::Download::from_headers(resp.headers());
+        let mut body = resp.into_body();
+        while let Some(chunk) = body.data().await {
+            writer.write_all(&chunk?).await?;
+        }
+        writer.flush().await?;
+        Ok(download)
+    }
+
+    pub(crate) async fn request<D: Deserialize, T: AsRef<[u8]> + 'static>(
+        &self,
+        method: Method,
+        path_and_query: T,
+    ) -> Fallible<D> {
+        self.request_with_body(method, path_and_query, Body::empty())
+            .await
+    }
+
+    pub(crate) async fn request_with_body<D: Deserialize, T: AsRef<[u8]> + 'static>(
+        &self,
+        method: Method,
+        path_and_query: T,
+        body: impl Into<Body>,
+    ) -> Fallible<D> {
+        let body = self
+            .raw_request(method, path_and_query, body.into())
+            .await?;
+        Ok(serde_json::from_reader(body.reader())?)
+    }
+
+    pub(crate) async fn request_without_response<T: AsRef<[u8]> + 'static>(
+        &self,
+        method: Method,
+        path_and_query: T,
+        body: impl Into<Body>,
+    ) -> Fallible<()> {
+        self.raw_request(method, path_and_query, body.into())
+            .await?;
+        Ok(())
+    }
+
     pub async fn get_all_events(
         &self,
         since: Option<u64>,
@@ -115,12 +233,7 @@ impl Client {
         if !events.is_empty() {
             let events = serde_json::to_string(&events)?
                 .chars()
-                .filter(|e| match e {
-                    '\"' => false,
-                    '[' => false,
-                    ']' => false,
-                    _ => true,
-                })
+                .filter(|e| !matches!(e, '\"' | '[' | ']'))
                 .collect::<String>();
             path_and_query.push(query_chars.next_char());
             path_and_query.push_str("events=");
@@ -139,12 +252,758 @@ impl Client {
         self.request(Method::GET, path_and_query).await
     }
 
+    async fn last_event_id(&self) -> Fallible<Option<u64>> {
+        Ok(self
+            .get_all_events(None, Some(1))
+            .await?
+            .last()
+            .map(|event| event.id))
+    }
+
     pub fn subscribe_to(self, events: impl Into<Vec<EventType>>) -> EventStream {
-        EventStream::new(self, events.into())
+        EventStream::new(self, Source::Events(events.into()))
     }
 
     pub fn subscribe_to_all(self) -> EventStream {
-        EventStream::new(self, EMPTY_EVENT_SUBSCRIPTION.clone())
+        EventStream::new(self, Source::Events(EMPTY_EVENT_SUBSCRIPTION.clone()))
+    }
+
+    /// Returns `LocalChangeDetected` and `RemoteChangeDetected` events, which
+    /// have their own event ids and buffer.
+    pub async fn get_disk_events(
+        &self,
+        since: Option<u64>,
+        limit: Option<u64>,
+    ) -> Fallible<Vec<Event>> {
+        let mut path_and_query = EVENTS_DISK_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        if let Some(since) = since {
+            query_chars.push_param(&mut path_and_query, "since", &since.to_string());
+        }
+        if let Some(limit) = limit {
+            query_chars.push_param(&mut path_and_query, "limit", &limit.to_string());
+        }
+        self.request(Method::GET, path_and_query).await
+    }
+
+    /// Like [`Client::subscribe_to_all`] but for the events returned by
+    /// [`Client::get_disk_events`].
+    pub fn subscribe_to_disk_events(self) -> EventStream {
+        EventStream::new(self, Source::DiskEvents)
+    }
+
+    /// Completes `prefix` to the existing paths on the machine syncthing runs on.
+    pub async fn browse_system_path(&self, prefix: &str) -> Fallible<system::browse::Browse> {
+        let mut path_and_query = SYSTEM_BROWSE_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "current", prefix);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub async fn get_pending_devices(&self) -> Fallible<cluster::pending::PendingDevices> {
+        self.request(Method::GET, CLUSTER_PENDING_DEVICES_PATH)
+            .await
+    }
+
+    /// Without `device` the folders offered by any device are returned.
+    pub async fn get_pending_folders(
+        &self,
+        device: Option<&str>,
+    ) -> Fallible<cluster::pending::PendingFolders> {
+        let mut path_and_query = CLUSTER_PENDING_FOLDERS_PATH.to_owned();
+        if let Some(device) = device {
+            QueryChars::new().push_param(&mut path_and_query, "device", device);
+        }
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub async fn dismiss_pending_device(&self, device: &str) -> Fallible<()> {
+        let mut path_and_query = CLUSTER_PENDING_DEVICES_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "device", device);
+        self.request_without_response(Method::DELETE, path_and_query, Body::empty())
+            .await
+    }
+
+    /// Without `device` the offers of all devices are dismissed.
+    pub async fn dismiss_pending_folder(&self, folder: &str, device: Option<&str>) -> Fallible<()> {
+        let mut path_and_query = CLUSTER_PENDING_FOLDERS_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        if let Some(device) = device {
+            query_chars.push_param(&mut path_and_query, "device", device);
+        }
+        self.request_without_response(Method::DELETE, path_and_query, Body::empty())
+            .await
+    }
+
+    /// Adds the pending `device` to the configuration under the name it
+    /// introduced itself with.
+    pub async fn accept_pending_device(&self, device: &str) -> Fallible<()> {
+        let pending_devices = self.get_pending_devices().await?;
+        let pending_device = pending_devices
+            .get(device)
+            .ok_or_else(|| anyhow!("device '{}' is not pending", device))?;
+        let body = serde_json::to_vec(&config::NewDeviceConfiguration {
+            device_id: device,
+            name: &pending_device.name,
+            addresses: &["dynamic"],
+        })?;
+        self.request_without_response(Method::POST, CONFIG_DEVICES_PATH, body)
+            .await
+    }
+
+    /// Shares the pending `folder` with `device`. If the folder is not
+    /// configured yet it is added at `path` under the offered label.
+    ///
+    /// Encrypted offers are rejected, they need an encryption password or a
+    /// receive encrypted folder which this helper does not set up.
+    pub async fn accept_pending_folder(
+        &self,
+        folder: &str,
+        device: &str,
+        path: &str,
+    ) -> Fallible<()> {
+        let pending_folders = self.get_pending_folders(Some(device)).await?;
+        let offer = pending_folders
+            .get(folder)
+            .and_then(|pending_folder| pending_folder.offered_by.get(device))
+            .ok_or_else(|| anyhow!("folder '{}' is not offered by '{}'", folder, device))?;
+        if offer.receive_encrypted || offer.remote_encrypted {
+            bail!(
+                "folder '{}' is offered encrypted by '{}', which is not supported",
+                folder,
+                device
+            )
+        }
+        let new_device = config::FolderDeviceConfiguration {
+            device_id: device.to_owned(),
+            ..Default::default()
+        };
+        let configured = self
+            .get_config_folders()
+            .await?
+            .into_iter()
+            .find(|config| config.id == folder);
+        if let Some(mut configured) = configured {
+            configured.devices.push(new_device);
+            let path_and_query = format!("{}/{}", CONFIG_FOLDERS_PATH, percent_encode(folder));
+            let body = serde_json::to_vec(&config::FolderDevicesPatch {
+                devices: &configured.devices,
+            })?;
+            self.request_without_response(Method::PATCH, path_and_query, body)
+                .await
+        } else {
+            let body = serde_json::to_vec(&config::NewFolderConfiguration {
+                id: folder,
+                label: &offer.label,
+                path,
+                devices: vec![new_device],
+            })?;
+            self.request_without_response(Method::POST, CONFIG_FOLDERS_PATH, body)
+                .await
+        }
+    }
+
+    pub async fn get_config_folders(&self) -> Fallible<Vec<config::FolderConfiguration>> {
+        self.request(Method::GET, CONFIG_FOLDERS_PATH).await
+    }
+
+    /// Returns the global file tree of `folder` below `prefix`, descending
+    /// `levels` directories deep. Without `levels` the whole tree is returned.
+    pub async fn browse_db(
+        &self,
+        folder: &str,
+        prefix: Option<&str>,
+        levels: Option<u64>,
+    ) -> Fallible<db::browse::Browse> {
+        let mut path_and_query = DB_BROWSE_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        if let Some(prefix) = prefix {
+            query_chars.push_param(&mut path_and_query, "prefix", prefix);
+        }
+        if let Some(levels) = levels {
+            query_chars.push_param(&mut path_and_query, "levels", &levels.to_string());
+        }
+        self.request(Method::GET, path_and_query).await
+    }
+
+    /// Walks the global file tree of `folder` below `prefix` one directory
+    /// at a time. With `max_depth` set, directories deeper than `max_depth`
+    /// levels below `prefix` are not descended into.
+    pub fn walk_db(
+        self,
+        folder: impl Into<String>,
+        prefix: impl Into<String>,
+        max_depth: Option<usize>,
+    ) -> TreeStream {
+        TreeStream::new(self, folder.into(), prefix.into(), max_depth)
+    }
+
+    /// Without `device` the completion of the local device is returned,
+    /// without `folder` the completion over all folders.
+    pub async fn get_db_completion(
+        &self,
+        device: Option<&str>,
+        folder: Option<&str>,
+    ) -> Fallible<db::completion::Completion> {
+        let mut path_and_query = DB_COMPLETION_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        if let Some(device) = device {
+            query_chars.push_param(&mut path_and_query, "device", device);
+        }
+        if let Some(folder) = folder {
+            query_chars.push_param(&mut path_and_query, "folder", folder);
+        }
+        self.request(Method::GET, path_and_query).await
+    }
+
+    /// Returns the completion of every remote device for every running folder
+    /// it shares, keyed by folder id and device id.
+    pub async fn get_db_completions(
+        &self,
+    ) -> Fallible<HashMap<String, HashMap<DeviceID, db::completion::Completion>>> {
+        // the local device is the only configured device without a connection entry
+        let connections = self.get_system_connections().await?.connections;
+        let mut completions = HashMap::new();
+        for folder in self.get_config_folders().await? {
+            // syncthing only reports the completion of running folders
+            if folder.paused {
+                continue;
+            }
+            let mut folder_completions = HashMap::new();
+            for device in folder.devices {
+                if !connections.contains_key(&device.device_id) {
+                    continue;
+                }
+                let completion = self
+                    .get_db_completion(Some(&device.device_id), Some(&folder.id))
+                    .await?;
+                folder_completions.insert(device.device_id, completion);
+            }
+            completions.insert(folder.id, folder_completions);
+        }
+        Ok(completions)
+    }
+
+    pub async fn get_db_file(
+        &self,
+        folder: &str,
+        file: &str,
+    ) -> Fallible<db::file::FileInfoResponse> {
+        let mut path_and_query = DB_FILE_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        query_chars.push_param(&mut path_and_query, "file", file);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub async fn get_ignores(&self, folder: &str) -> Fallible<db::ignores::Ignores> {
+        let mut path_and_query = DB_IGNORES_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "folder", folder);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub async fn set_ignores(
+        &self,
+        folder: &str,
+        lines: &[String],
+    ) -> Fallible<db::ignores::Ignores> {
+        let mut path_and_query = DB_IGNORES_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "folder", folder);
+        let body = serde_json::to_vec(&db::ignores::SetIgnores { ignore: lines })?;
+        self.request_with_body(Method::POST, path_and_query, body)
+            .await
+    }
+
+    /// Sets the ignore patterns of every folder in `folders` to `lines`.
+    /// Returns the ids of the folders whose patterns differed.
+    pub async fn apply_ignore_template(
+        &self,
+        folders: impl IntoIterator<Item = impl AsRef<str>>,
+        lines: &[String],
+    ) -> Fallible<Vec<String>> {
+        let mut changed = Vec::new();
+        for folder in folders {
+            let folder = folder.as_ref();
+            if self.get_ignores(folder).await?.ignore != lines {
+                self.set_ignores(folder, lines).await?;
+                changed.push(folder.to_owned());
+            }
+        }
+        Ok(changed)
+    }
+
+    pub async fn get_db_need(
+        &self,
+        folder: &str,
+        page: Option<u64>,
+        perpage: Option<u64>,
+    ) -> Fallible<db::need::Need> {
+        let mut path_and_query = DB_NEED_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        query_chars.push_page_params(&mut path_and_query, page, perpage);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    /// Streams the needed files of `folder` in download order, fetching
+    /// `perpage` files at a time.
+    pub fn stream_db_need(
+        self,
+        folder: impl Into<String>,
+        perpage: NonZeroU64,
+    ) -> PageStream<db::need::NeedItem> {
+        let folder = folder.into();
+        PageStream::new(self, perpage, move |client, page, perpage| {
+            let folder = folder.clone();
+            Box::pin(async move {
+                let data = client
+                    .get_db_need(&folder, Some(page), Some(perpage))
+                    .await
+                    .map(db::need::Need::into_items);
+                (client, data)
+            })
+        })
+    }
+
+    /// Moves `path` to the front of the download queue of `folder` and
+    /// returns the updated need list.
+    pub async fn prioritize_file(&self, folder: &str, path: &str) -> Fallible<db::need::Need> {
+        let mut path_and_query = DB_PRIO_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        query_chars.push_param(&mut path_and_query, "file", path);
+        self.request(Method::POST, path_and_query).await
+    }
+
+    /// Like [`Client::prioritize_file`] but waits until syncthing finished
+    /// the item and reports whether syncing it succeeded. Fails right away if
+    /// `path` is not needed.
+    ///
+    /// Never resolves if the item is not synced, e.g. because the folder is
+    /// paused or no device has it, so callers should wrap it in a timeout.
+    pub async fn prioritize_file_and_wait(&self, folder: &str, path: &str) -> Fallible<()> {
+        let mut since = self.last_event_id().await?;
+        let need = self.prioritize_file(folder, path).await?;
+        if !need.into_items().iter().any(|item| item.file.name == path) {
+            bail!("'{}' is not needed in folder '{}'", path, folder)
+        }
+        loop {
+            for event in self.get_all_events(since, None).await? {
+                since = Some(event.id);
+                match event.data {
+                    EventData::ItemFinished(item_finished)
+                        if item_finished.folder == folder && item_finished.item == path =>
+                    {
+                        return match item_finished.error {
+                            Some(error) => Err(anyhow!("syncing '{}' failed: {}", path, error)),
+                            None => Ok(()),
+                        };
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Lists the files `device` still needs from the local device.
+    pub async fn get_db_remote_need(
+        &self,
+        folder: &str,
+        device: &str,
+        page: Option<u64>,
+        perpage: Option<u64>,
+    ) -> Fallible<db::file::FilePage> {
+        let mut path_and_query = DB_REMOTE_NEED_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        query_chars.push_param(&mut path_and_query, "device", device);
+        query_chars.push_page_params(&mut path_and_query, page, perpage);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub fn stream_db_remote_need(
+        self,
+        folder: impl Into<String>,
+        device: impl Into<String>,
+        perpage: NonZeroU64,
+    ) -> PageStream<db::file::FileInfo> {
+        let folder = folder.into();
+        let device = device.into();
+        PageStream::new(self, perpage, move |client, page, perpage| {
+            let folder = folder.clone();
+            let device = device.clone();
+            Box::pin(async move {
+                let data = client
+                    .get_db_remote_need(&folder, &device, Some(page), Some(perpage))
+                    .await
+                    .map(|file_page| file_page.files);
+                (client, data)
+            })
+        })
+    }
+
+    /// Lists the locally changed files of a receive only folder.
+    pub async fn get_db_local_changed(
+        &self,
+        folder: &str,
+        page: Option<u64>,
+        perpage: Option<u64>,
+    ) -> Fallible<db::file::FilePage> {
+        let mut path_and_query = DB_LOCAL_CHANGED_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        query_chars.push_page_params(&mut path_and_query, page, perpage);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub fn stream_db_local_changed(
+        self,
+        folder: impl Into<String>,
+        perpage: NonZeroU64,
+    ) -> PageStream<db::file::FileInfo> {
+        let folder = folder.into();
+        PageStream::new(self, perpage, move |client, page, perpage| {
+            let folder = folder.clone();
+            Box::pin(async move {
+                let data = client
+                    .get_db_local_changed(&folder, Some(page), Some(perpage))
+                    .await
+                    .map(|file_page| file_page.files);
+                (client, data)
+            })
+        })
+    }
+
+    /// Makes the local state of a send only folder the global state,
+    /// overwriting any remote changes.
+    pub async fn override_folder(&self, folder: &str) -> Fallible<()> {
+        let mut path_and_query = DB_OVERRIDE_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "folder", folder);
+        self.request_without_response(Method::POST, path_and_query, Body::empty())
+            .await
+    }
+
+    /// Reverts the local changes of a receive only folder to the global state.
+    pub async fn revert_folder(&self, folder: &str) -> Fallible<()> {
+        let mut path_and_query = DB_REVERT_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "folder", folder);
+        self.request_without_response(Method::POST, path_and_query, Body::empty())
+            .await
+    }
+
+    /// Lists the global versions an override of the send only `folder` would
+    /// discard, i.e. its needed files.
+    pub async fn preview_override(&self, folder: &str) -> Fallible<db::preview::Preview> {
+        let items = self
+            .clone()
+            .stream_db_need(folder, perpage())
+            .try_collect()
+            .await?
+            .into_iter()
+            .map(|need_item| need_item.file)
+            .collect();
+        Ok(db::preview::Preview {
+            folder: folder.to_owned(),
+            action: db::preview::PreviewAction::Override,
+            items,
+        })
+    }
+
+    /// Lists the local files a revert of `folder` would revert.
+    pub async fn preview_revert(&self, folder: &str) -> Fallible<db::preview::Preview> {
+        let items = self
+            .clone()
+            .stream_db_local_changed(folder, perpage())
+            .try_collect()
+            .await?;
+        Ok(db::preview::Preview {
+            folder: folder.to_owned(),
+            action: db::preview::PreviewAction::Revert,
+            items,
+        })
+    }
+
+    /// Carries out the previewed action if a fresh preview of the same folder
+    /// holds exactly `expected_items` items, i.e. nothing changed in between.
+    pub async fn confirm_preview(
+        &self,
+        preview: &db::preview::Preview,
+        expected_items: usize,
+    ) -> Fallible<()> {
+        use db::preview::PreviewAction;
+        let folder = preview.folder();
+        let fresh = match preview.action() {
+            PreviewAction::Override => self.preview_override(folder).await?,
+            PreviewAction::Revert => self.preview_revert(folder).await?,
+        };
+        if fresh.items().len() != expected_items {
+            bail!(
+                "preview of folder '{}' holds {} items but {} were expected",
+                folder,
+                fresh.items().len(),
+                expected_items
+            )
+        }
+        match preview.action() {
+            PreviewAction::Override => self.override_folder(folder).await,
+            PreviewAction::Revert => self.revert_folder(folder).await,
+        }
+    }
+
+    /// Scans `folder`, limited to the paths in `subs` if not empty, and
+    /// delays the next scheduled scan by `next` seconds.
+    pub async fn scan(
+        &self,
+        folder: &str,
+        subs: impl IntoIterator<Item = impl AsRef<str>>,
+        next: Option<u64>,
+    ) -> Fallible<()> {
+        let mut path_and_query = DB_SCAN_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        for sub in subs {
+            query_chars.push_param(&mut path_and_query, "sub", sub.as_ref());
+        }
+        if let Some(next) = next {
+            query_chars.push_param(&mut path_and_query, "next", &next.to_string());
+        }
+        self.request_without_response(Method::POST, path_and_query, Body::empty())
+            .await
+    }
+
+    /// Like [`Client::scan`] but also reports how the scan went. The scan
+    /// request only returns once the scan is done, the report is built from the
+    /// last time the folder left the scanning state until then.
+    pub async fn scan_and_wait(
+        &self,
+        folder: &str,
+        subs: impl IntoIterator<Item = impl AsRef<str>>,
+        next: Option<u64>,
+    ) -> Fallible<db::scan::ScanReport> {
+        let mut since = self.last_event_id().await?;
+        let started = Instant::now();
+        self.scan(folder, subs, next).await?;
+        let until = self.last_event_id().await?;
+        let mut errors = Vec::new();
+        let mut progress = None;
+        let mut report = None;
+        while since < until {
+            // the default subscription also includes `StateChanged` and
+            // `FolderScanProgress` and unlike a filtered one it exists already
+            for event in self.get_all_events(since, None).await? {
+                if Some(event.id) > until {
+                    since = until;
+                    break;
+                }
+                since = Some(event.id);
+                match event.data {
+                    EventData::FolderScanProgress(scan_progress)
+                        if scan_progress.folder_id == folder =>
+                    {
+                        progress = Some(scan_progress)
+                    }
+                    EventData::StateChanged(state_changed) if state_changed.folder_id == folder => {
+                        if state_changed.to == FolderState::Scanning {
+                            errors.clear();
+                            progress = None;
+                        }
+                        errors.extend(state_changed.error);
+                        if state_changed.from == FolderState::Scanning {
+                            let duration = state_changed
+                                .duration
+                                .map_or_else(|| started.elapsed(), Duration::from_secs_f64);
+                            report = Some(db::scan::ScanReport {
+                                duration,
+                                errors: errors.clone(),
+                                progress: progress.take(),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        report.ok_or_else(|| anyhow!("no finished scan of folder '{}' was reported", folder))
+    }
+
+    pub async fn get_db_status(&self, folder_id: &str) -> Fallible<FolderSummaryData> {
+        let mut path_and_query = DB_STATUS_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "folder", folder_id);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub async fn get_debug_peer_completion(
+        &self,
+    ) -> Fallible<debug::peer_completion::PeerCompletion> {
+        self.request(Method::GET, DEBUG_PEER_COMPLETION_PATH).await
+    }
+
+    pub async fn get_debug_http_metrics(&self) -> Fallible<debug::http_metrics::HttpMetrics> {
+        self.request(Method::GET, DEBUG_HTTP_METRICS_PATH).await
+    }
+
+    pub async fn get_debug_file(
+        &self,
+        folder: &str,
+        file: &str,
+    ) -> Fallible<debug::file::DebugFile> {
+        let mut path_and_query = DEBUG_FILE_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        query_chars.push_param(&mut path_and_query, "file", file);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    /// Streams a profile or support bundle into `writer`. The CPU profile
+    /// is sampled for 30 seconds before the download starts.
+    pub async fn download_debug(
+        &self,
+        dump: debug::download::Dump,
+        writer: &mut (impl AsyncWrite + Unpin),
+    ) -> Fallible<debug::download::Download> {
+        self.download(debug_path(dump), writer).await
+    }
+
+    /// Like [`Client::download_debug`] but writes to a newly created file at
+    /// `path`, failing if it already exists. The file is only created once
+    /// syncthing accepted the request and removed again if the download fails.
+    pub async fn download_debug_to_file(
+        &self,
+        dump: debug::download::Dump,
+        path: impl AsRef<Path>,
+    ) -> Fallible<debug::download::Download> {
+        let path = path.as_ref();
+        let resp = self
+            .send(Method::GET, debug_path(dump), Body::empty())
+            .await?;
+        let mut file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .await?;
+        let download = Self::write_body(resp, &mut file).await;
+        if download.is_err() {
+            drop(file);
+            let _ = tokio::fs::remove_file(path).await;
+        }
+        download
+    }
+
+    pub async fn get_folder_errors(
+        &self,
+        folder: &str,
+        page: Option<u64>,
+        perpage: Option<u64>,
+    ) -> Fallible<folder::errors::FolderErrors> {
+        let mut path_and_query = FOLDER_ERRORS_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "folder", folder);
+        query_chars.push_page_params(&mut path_and_query, page, perpage);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub fn stream_folder_errors(
+        self,
+        folder: impl Into<String>,
+        perpage: NonZeroU64,
+    ) -> PageStream<FolderError> {
+        let folder = folder.into();
+        PageStream::new(self, perpage, move |client, page, perpage| {
+            let folder = folder.clone();
+            Box::pin(async move {
+                let data = client
+                    .get_folder_errors(&folder, Some(page), Some(perpage))
+                    .await
+                    .map(|folder_errors| folder_errors.errors);
+                (client, data)
+            })
+        })
+    }
+
+    pub async fn get_folder_versions(&self, folder: &str) -> Fallible<folder::versions::Versions> {
+        let mut path_and_query = FOLDER_VERSIONS_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "folder", folder);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    /// Restores every path in `versions` to the version archived at the given time.
+    pub async fn restore_versions(
+        &self,
+        folder: &str,
+        versions: &HashMap<String, DateTime<FixedOffset>>,
+    ) -> Fallible<folder::versions::RestoreErrors> {
+        let mut path_and_query = FOLDER_VERSIONS_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "folder", folder);
+        let body = serde_json::to_vec(versions)?;
+        self.request_with_body(Method::POST, path_and_query, body)
+            .await
+    }
+
+    /// Restores the file `prefix`, or every file below the directory `prefix`,
+    /// to its newest version that was modified before `before`. Files without
+    /// such a version are left alone, an empty `prefix` matches all files.
+    pub async fn restore_versions_before(
+        &self,
+        folder: &str,
+        prefix: &str,
+        before: DateTime<FixedOffset>,
+    ) -> Fallible<folder::versions::RestoreErrors> {
+        let prefix = prefix.trim_end_matches('/');
+        let directory = format!("{}/", prefix);
+        let versions = self
+            .get_folder_versions(folder)
+            .await?
+            .into_iter()
+            .filter(|(path, _)| prefix.is_empty() || path == prefix || path.starts_with(&directory))
+            .filter_map(|(path, versions)| {
+                versions
+                    .into_iter()
+                    .filter(|version| version.mod_time < before)
+                    .max_by_key(|version| version.mod_time)
+                    .map(|version| (path, version.version_time))
+            })
+            .collect::<HashMap<_, _>>();
+        if versions.is_empty() {
+            return Ok(HashMap::new());
+        }
+        self.restore_versions(folder, &versions).await
+    }
+
+    pub async fn get_stats_device(&self) -> Fallible<stats::device::DeviceStats> {
+        self.request(Method::GET, STATS_DEVICE_PATH).await
+    }
+
+    pub async fn get_stats_folder(&self) -> Fallible<stats::folder::FolderStats> {
+        self.request(Method::GET, STATS_FOLDER_PATH).await
+    }
+
+    pub async fn validate_device_id(
+        &self,
+        id: &str,
+    ) -> Fallible<svc::device_id::DeviceIdValidation> {
+        let mut path_and_query = SVC_DEVICE_ID_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "id", id);
+        self.request(Method::GET, path_and_query).await
+    }
+
+    pub async fn get_languages(&self) -> Fallible<svc::lang::Languages> {
+        self.request(Method::GET, SVC_LANG_PATH).await
+    }
+
+    /// Generates a random string of `length` characters, suitable for API
+    /// keys or folder ids.
+    pub async fn random_string(&self, length: u64) -> Fallible<String> {
+        let mut path_and_query = SVC_RANDOM_STRING_PATH.to_owned();
+        QueryChars::new().push_param(&mut path_and_query, "length", &length.to_string());
+        let random_string: svc::random::RandomString =
+            self.request(Method::GET, path_and_query).await?;
+        Ok(random_string.random)
+    }
+
+    /// Returns the usage report syncthing would send if usage reporting was enabled.
+    pub async fn get_usage_report(&self) -> Fallible<svc::report::Report> {
+        self.request(Method::GET, SVC_REPORT_PATH).await
     }
 
     pub async fn get_system_connections(&self) -> Fallible<system::connections::Connections> {
@@ -159,6 +1018,36 @@ impl Client {
         self.request(Method::GET, SYSTEM_DISCOVERY_PATH).await
     }
 
+    pub async fn post_system_discovery(&self, device: &str, addr: &str) -> Fallible<()> {
+        let mut path_and_query = SYSTEM_DISCOVERY_PATH.to_owned();
+        let mut query_chars = QueryChars::new();
+        query_chars.push_param(&mut path_and_query, "device", device);
+        query_chars.push_param(&mut path_and_query, "addr", addr);
+        self.request_without_response(Method::POST, path_and_query, Body::empty())
+            .await
+    }
+
+    /// Injects every address of the inventory file at `path` that is not yet
+    /// in the discovery cache. Returns the number of injected addresses.
+    pub async fn sync_discovery_inventory(&self, path: impl AsRef<Path>) -> Fallible<usize> {
+        let inventory: system::discovery::Inventory =
+            tokio::fs::read_to_string(path).await?.parse()?;
+        let discovery = self.get_system_discovery().await?;
+        let mut injected = 0;
+        for (device_id, addrs) in &inventory.devices {
+            let known = discovery.get(device_id);
+            for addr in addrs {
+                if matches!(known, Some(known) if known.addresses.contains(addr)) {
+                    continue;
+                }
+                self.post_system_discovery(device_id, &addr.to_string())
+                    .await?;
+                injected += 1;
+            }
+        }
+        Ok(injected)
+    }
+
     pub async fn get_system_log(&self) -> Fallible<system::log::Log> {
         self.request(Method::GET, SYSTEM_LOG_PATH).await
     }
@@ -167,6 +1056,26 @@ impl Client {
         self.request(Method::GET, SYSTEM_ERROR_PATH).await
     }
 
+    pub async fn post_system_error(&self, message: impl Into<String>) -> Fallible<()> {
+        self.request_without_response(Method::POST, SYSTEM_ERROR_PATH, message.into())
+            .await
+    }
+
+    pub async fn clear_system_errors(&self) -> Fallible<()> {
+        self.request_without_response(Method::POST, SYSTEM_ERROR_CLEAR_PATH, Body::empty())
+            .await
+    }
+
+    /// Polls the system error list every `interval` and yields each entry once.
+    /// Entries already present at the first poll are skipped.
+    pub fn subscribe_to_system_errors(self, interval: Duration) -> ErrorStream {
+        ErrorStream::new(self, interval)
+    }
+
+    pub async fn get_system_paths(&self) -> Fallible<system::paths::Paths> {
+        self.request(Method::GET, SYSTEM_PATHS_PATH).await
+    }
+
     pub async fn get_system_ping(&self) -> Fallible<system::ping::Ping> {
         self.request(Method::GET, SYSTEM_PING_PATH).await
     }
@@ -178,4 +1087,47 @@ impl Client {
     pub async fn get_system_version(&self) -> Fallible<system::version::Version> {
         self.request(Method::GET, SYSTEM_VERSION_PATH).await
     }
+
+    pub async fn get_metrics(&self) -> Fallible<metrics::Metrics> {
+        let body = self
+            .raw_request(Method::GET, METRICS_PATH, Body::empty())
+            .await?;
+        let mut text = String::new();
+        body.reader().read_to_string(&mut text)?;
+        text.parse()
+    }
+
+    pub async fn health(&self) -> Fallible<noauth::health::Health> {
+        self.request(Method::GET, NOAUTH_HEALTH_PATH).await
+    }
+
+    /// Combines [`Client::health`], [`Client::get_system_ping`] and the
+    /// error lists of all running folders into a single verdict.
+    pub async fn readiness(&self) -> noauth::health::Readiness {
+        use noauth::health::Readiness;
+        if let Err(err) = self.health().await {
+            return Readiness::Unhealthy(err);
+        }
+        match self.folder_errors().await {
+            Ok(folder_errors) if folder_errors.is_empty() => Readiness::Ready,
+            Ok(folder_errors) => Readiness::FolderErrors(folder_errors),
+            Err(err) => Readiness::Unavailable(err),
+        }
+    }
+
+    async fn folder_errors(&self) -> Fallible<HashMap<String, Vec<FolderError>>> {
+        self.get_system_ping().await?;
+        let mut folder_errors = HashMap::new();
+        for folder in self.get_config_folders().await? {
+            // syncthing only reports errors of running folders
+            if folder.paused {
+                continue;
+            }
+            let errors = self.get_folder_errors(&folder.id, None, None).await?;
+            if !errors.errors.is_empty() {
+                folder_errors.insert(folder.id, errors.errors);
+            }
+        }
+        Ok(folder_errors)
+    }
 }
@@ -0,0 +1,91 @@
+use crate::rest::system::error::Error;
+use crate::rest::system::Entry;
+use crate::{Client, Fallible};
+use futures_core::future::BoxFuture;
+use futures_core::ready;
+use futures_core::stream::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+async fn receive(client: Client, delay: Option<Duration>) -> (Client, Fallible<Error>) {
+    if let Some(delay) = delay {
+        tokio::time::delay_for(delay).await;
+    }
+    let data = client.get_system_error().await;
+    (client, data)
+}
+
+enum State {
+    Buffer(Option<Client>, Vec<Entry>),
+    Future(BoxFuture<'static, (Client, Fallible<Error>)>),
+}
+
+pub struct ErrorStream {
+    state: State,
+    interval: Duration,
+    primed: bool,
+    last: Option<Entry>,
+}
+
+impl ErrorStream {
+    pub(crate) fn new(client: Client, interval: Duration) -> Self {
+        Self {
+            state: State::Future(Box::pin(receive(client, None))),
+            interval,
+            primed: false,
+            last: None,
+        }
+    }
+
+    fn unseen(&mut self, mut entries: Vec<Entry>) -> Vec<Entry> {
+        // entries present at the first poll are only remembered, not yielded
+        if !self.primed {
+            self.primed = true;
+            self.last = entries.pop();
+            return Vec::new();
+        }
+        // syncthing appends to the error list, so everything after the last
+        // entry we handed out is new. If it is gone the list got cleared.
+        let start = self
+            .last
+            .as_ref()
+            .and_then(|last| entries.iter().rposition(|entry| entry == last))
+            .map_or(0, |position| position + 1);
+        let unseen = entries.split_off(start);
+        if let Some(last) = unseen.last() {
+            self.last = Some(last.clone());
+        }
+        unseen
+    }
+}
+
+impl Stream for ErrorStream {
+    type Item = Fallible<Entry>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match &mut self.state {
+                State::Future(fut) => match ready!(fut.as_mut().poll(cx)) {
+                    (client, Ok(error)) => {
+                        let mut unseen = self.unseen(error.errors);
+                        unseen.reverse();
+                        self.state = State::Buffer(Some(client), unseen);
+                    }
+                    (client, Err(err)) => {
+                        self.state = State::Future(Box::pin(receive(client, Some(self.interval))));
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+                State::Buffer(client, entries) => {
+                    if let Some(entry) = entries.pop() {
+                        return Poll::Ready(Some(Ok(entry)));
+                    } else {
+                        let client = client.take().unwrap();
+                        self.state = State::Future(Box::pin(receive(client, Some(self.interval))));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,20 +6,28 @@ use futures_core::stream::Stream;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+pub(crate) enum Source {
+    Events(Vec<EventType>),
+    DiskEvents,
+}
+
 async fn receive(
     client: Client,
     since: Option<u64>,
     limit: Option<u64>,
-    events: Vec<EventType>,
-) -> (Client, Vec<EventType>, Fallible<Vec<Event>>) {
-    let data = client.get_events(since, limit, &events).await;
-    (client, events, data)
+    source: Source,
+) -> (Client, Source, Fallible<Vec<Event>>) {
+    let data = match &source {
+        Source::Events(events) => client.get_events(since, limit, events).await,
+        Source::DiskEvents => client.get_disk_events(since, limit).await,
+    };
+    (client, source, data)
 }
 
 #[allow(clippy::large_enum_variant)]
 enum State {
-    Buffer(Option<(Client, Vec<EventType>)>, Vec<Event>),
-    Future(BoxFuture<'static, (Client, Vec<EventType>, Fallible<Vec<Event>>)>),
+    Buffer(Option<(Client, Source)>, Vec<Event>),
+    Future(BoxFuture<'static, (Client, Source, Fallible<Vec<Event>>)>),
 }
 
 //TODO:self correction mechanism see: https://docs.syncthing.net/rest/events-get.html#events-get
@@ -29,9 +37,9 @@ pub struct EventStream {
 }
 
 impl EventStream {
-    pub(crate) fn new(client: Client, events: Vec<EventType>) -> Self {
+    pub(crate) fn new(client: Client, source: Source) -> Self {
         Self {
-            state: State::Future(Box::pin(receive(client, None, None, events))),
+            state: State::Future(Box::pin(receive(client, None, None, source))),
             since: None,
         }
     }
@@ -41,26 +49,28 @@ impl Stream for EventStream {
     type Item = Fallible<Event>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        match &mut self.state {
-            State::Future(fut) => match ready!(fut.as_mut().poll(cx)) {
-                (client, events, Ok(mut data)) => {
-                    data.reverse();
-                    self.state = State::Buffer(Some((client, events)), data);
-                    Poll::Pending
-                }
-                (client, events, Err(err)) => {
-                    self.state = State::Future(Box::pin(receive(client, self.since, None, events)));
-                    Poll::Ready(Some(Err(err)))
-                }
-            },
-            State::Buffer(connection_events, data) => {
-                if let Some(event) = data.pop() {
-                    self.since = Some(event.id);
-                    Poll::Ready(Some(Ok(event)))
-                } else {
-                    let (client, events) = connection_events.take().unwrap();
-                    self.state = State::Future(Box::pin(receive(client, self.since, None, events)));
-                    Poll::Pending
+        loop {
+            match &mut self.state {
+                State::Future(fut) => match ready!(fut.as_mut().poll(cx)) {
+                    (client, source, Ok(mut data)) => {
+                        data.reverse();
+                        self.state = State::Buffer(Some((client, source)), data);
+                    }
+                    (client, source, Err(err)) => {
+                        self.state =
+                            State::Future(Box::pin(receive(client, self.since, None, source)));
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+                State::Buffer(connection_source, data) => {
+                    if let Some(event) = data.pop() {
+                        self.since = Some(event.id);
+                        return Poll::Ready(Some(Ok(event)));
+                    } else {
+                        let (client, source) = connection_source.take().unwrap();
+                        self.state =
+                            State::Future(Box::pin(receive(client, self.since, None, source)));
+                    }
                 }
             }
         }
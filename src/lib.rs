@@ -1,13 +1,19 @@
 mod client;
+mod error_stream;
 mod event_stream;
+mod page_stream;
 pub mod rest;
 mod routes;
 #[cfg(test)]
 mod tests;
+mod tree_stream;
 mod utils;
 
 pub use client::Client;
+pub use error_stream::*;
 pub use event_stream::*;
+pub use page_stream::*;
+pub use tree_stream::*;
 
 //TODO: add log + feature flag
 
@@ -0,0 +1,103 @@
+use crate::{Client, Fallible};
+use futures_core::future::BoxFuture;
+use futures_core::ready;
+use futures_core::stream::Stream;
+use std::future::Future;
+use std::num::NonZeroU64;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub(crate) type Page<T> = BoxFuture<'static, (Client, Fallible<Vec<T>>)>;
+type Fetch<T> = Box<dyn Fn(Client, u64, u64) -> Page<T> + Send>;
+
+enum State<T> {
+    Buffer(Option<Client>, Vec<T>),
+    Future(Page<T>),
+}
+
+/// Walks all pages of a paginated endpoint, stopping at the first page that
+/// is empty or not full, or after the first error.
+pub struct PageStream<T> {
+    state: State<T>,
+    fetch: Fetch<T>,
+    page: u64,
+    perpage: NonZeroU64,
+}
+
+impl<T> Unpin for PageStream<T> {}
+
+impl<T> PageStream<T> {
+    pub(crate) fn new(
+        client: Client,
+        perpage: NonZeroU64,
+        fetch: impl Fn(Client, u64, u64) -> Page<T> + Send + 'static,
+    ) -> Self {
+        Self {
+            state: State::Future(fetch(client, 1, perpage.get())),
+            fetch: Box::new(fetch),
+            page: 1,
+            perpage,
+        }
+    }
+}
+
+impl<T> PageStream<T> {
+    /// Collects all items, failing on the first error.
+    pub(crate) async fn try_collect(mut self) -> Fallible<Vec<T>> {
+        let mut items = Vec::new();
+        while let Some(item) = Next(&mut self).await {
+            items.push(item?);
+        }
+        Ok(items)
+    }
+}
+
+struct Next<'a, T>(&'a mut PageStream<T>);
+
+impl<T> Future for Next<'_, T> {
+    type Output = Option<Fallible<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.0).poll_next(cx)
+    }
+}
+
+impl<T> Stream for PageStream<T> {
+    type Item = Fallible<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match &mut self.state {
+                State::Future(fut) => match ready!(fut.as_mut().poll(cx)) {
+                    (client, Ok(mut data)) => {
+                        let client = if !data.is_empty() && data.len() as u64 >= self.perpage.get()
+                        {
+                            Some(client)
+                        } else {
+                            None
+                        };
+                        data.reverse();
+                        self.state = State::Buffer(client, data);
+                    }
+                    (_, Err(err)) => {
+                        self.state = State::Buffer(None, Vec::new());
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+                State::Buffer(client, data) => {
+                    if let Some(item) = data.pop() {
+                        return Poll::Ready(Some(Ok(item)));
+                    }
+                    match client.take() {
+                        Some(client) => {
+                            self.page += 1;
+                            self.state =
+                                State::Future((self.fetch)(client, self.page, self.perpage.get()));
+                        }
+                        None => return Poll::Ready(None),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,13 @@
+pub mod cluster;
+pub mod config;
+pub mod db;
+pub mod debug;
 pub mod events;
+pub mod folder;
+pub mod metrics;
+pub mod noauth;
+pub mod stats;
+pub mod svc;
 pub mod system;
 
 use serde::Deserialize;
@@ -9,7 +18,7 @@ use std::collections::HashMap;
 
 type FileName = String;
 //TODO: use separate type?
-type DeviceID = String;
+pub type DeviceID = String;
 type FolderName = String;
 type Folder = HashMap<FileName, File>;
 
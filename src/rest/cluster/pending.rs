@@ -0,0 +1,32 @@
+use crate::rest::DeviceID;
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+pub struct PendingDevice {
+    pub time: DateTime<FixedOffset>,
+    pub name: String,
+    pub address: String,
+}
+
+pub type PendingDevices = HashMap<DeviceID, PendingDevice>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct PendingFolderOffer {
+    pub time: DateTime<FixedOffset>,
+    pub label: String,
+    #[serde(default)]
+    pub receive_encrypted: bool,
+    #[serde(default)]
+    pub remote_encrypted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct PendingFolder {
+    pub offered_by: HashMap<DeviceID, PendingFolderOffer>,
+}
+
+pub type PendingFolders = HashMap<String, PendingFolder>;
@@ -0,0 +1,66 @@
+use crate::rest::DeviceID;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "lowercase"))]
+pub enum FolderType {
+    SendReceive,
+    SendOnly,
+    ReceiveOnly,
+    ReceiveEncrypted,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FolderDeviceConfiguration {
+    #[serde(rename = "deviceID")]
+    pub device_id: DeviceID,
+    #[serde(default)]
+    pub introduced_by: DeviceID,
+    #[serde(default)]
+    pub encryption_password: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VersioningConfiguration {
+    /// Empty if versioning is disabled.
+    #[serde(rename = "type")]
+    pub versioning_type: String,
+}
+
+//FIXME: complete
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct FolderConfiguration {
+    pub id: String,
+    pub label: String,
+    pub path: String,
+    #[serde(rename = "type")]
+    pub folder_type: FolderType,
+    pub devices: Vec<FolderDeviceConfiguration>,
+    pub versioning: VersioningConfiguration,
+    pub paused: bool,
+}
+
+/// A folder to add, syncthing fills in the defaults for everything else.
+#[derive(Debug, Serialize)]
+pub(crate) struct NewFolderConfiguration<'a> {
+    pub id: &'a str,
+    pub label: &'a str,
+    pub path: &'a str,
+    pub devices: Vec<FolderDeviceConfiguration>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct FolderDevicesPatch<'a> {
+    pub devices: &'a [FolderDeviceConfiguration],
+}
+
+/// A device to add, syncthing fills in the defaults for everything else.
+#[derive(Debug, Serialize)]
+pub(crate) struct NewDeviceConfiguration<'a> {
+    #[serde(rename = "deviceID")]
+    pub device_id: &'a str,
+    pub name: &'a str,
+    pub addresses: &'a [&'a str],
+}
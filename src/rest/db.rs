@@ -0,0 +1,23 @@
+pub mod browse;
+pub mod completion;
+pub mod file;
+pub mod ignores;
+pub mod need;
+pub mod preview;
+pub mod scan;
+
+use serde::Deserialize;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub enum FileType {
+    #[serde(rename = "FILE_INFO_TYPE_FILE")]
+    File,
+    #[serde(rename = "FILE_INFO_TYPE_DIRECTORY")]
+    Directory,
+    #[serde(rename = "FILE_INFO_TYPE_SYMLINK")]
+    Symlink,
+    #[serde(rename = "FILE_INFO_TYPE_SYMLINK_FILE")]
+    SymlinkFile,
+    #[serde(rename = "FILE_INFO_TYPE_SYMLINK_DIRECTORY")]
+    SymlinkDirectory,
+}
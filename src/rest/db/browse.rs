@@ -0,0 +1,17 @@
+use crate::rest::db::FileType;
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Entry {
+    pub name: String,
+    pub mod_time: DateTime<FixedOffset>,
+    pub size: u64,
+    #[serde(rename = "type")]
+    pub entry_type: FileType,
+    #[serde(default)]
+    pub children: Vec<Entry>,
+}
+
+pub type Browse = Vec<Entry>;
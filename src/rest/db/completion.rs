@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub enum RemoteState {
+    Unknown,
+    NotSharing,
+    Paused,
+    Valid,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Completion {
+    pub completion: f64,
+    pub global_bytes: u64,
+    pub global_items: u64,
+    pub need_bytes: u64,
+    pub need_deletes: u64,
+    pub need_items: u64,
+    pub remote_state: RemoteState,
+    pub sequence: u64,
+}
@@ -0,0 +1,219 @@
+use crate::rest::db::FileType;
+use crate::rest::DeviceID;
+use crate::utils;
+use chrono::{DateTime, FixedOffset};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+use std::cmp::Ordering;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter {
+    /// Short id of the device that made the change.
+    pub id: String,
+    pub value: u64,
+}
+
+/// A version vector as rendered by syncthing, e.g. `["ABCDEFG:1591278472"]`.
+#[derive(Debug, Clone, Default)]
+pub struct VersionVector {
+    pub counters: Vec<Counter>,
+}
+
+impl VersionVector {
+    pub fn counter(&self, id: &str) -> u64 {
+        self.counters
+            .iter()
+            .find(|counter| counter.id == id)
+            .map_or(0, |counter| counter.value)
+    }
+}
+
+impl PartialEq for VersionVector {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Concurrent vectors, i.e. conflicting changes, are unordered.
+impl PartialOrd for VersionVector {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut ordering = Ordering::Equal;
+        let ids = self.counters.iter().chain(&other.counters);
+        for id in ids.map(|counter| counter.id.as_str()) {
+            match (ordering, self.counter(id).cmp(&other.counter(id))) {
+                (_, Ordering::Equal) => {}
+                (Ordering::Equal, counter_ordering) => ordering = counter_ordering,
+                (ordering, counter_ordering) if ordering != counter_ordering => return None,
+                _ => {}
+            }
+        }
+        Some(ordering)
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionVector {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let counters = Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default();
+        let counters = counters
+            .iter()
+            .map(|counter| {
+                let (id, value) = counter
+                    .split_once(':')
+                    .ok_or_else(|| D::Error::custom(format!("invalid counter '{}'", counter)))?;
+                Ok(Counter {
+                    id: id.to_owned(),
+                    value: value.parse().map_err(D::Error::custom)?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { counters })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct FileInfo {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub file_type: FileType,
+    pub size: u64,
+    /// Octal permission bits, e.g. `0644`, missing with `no_permissions`.
+    pub permissions: Option<String>,
+    pub no_permissions: bool,
+    pub modified: DateTime<FixedOffset>,
+    /// Short id of the device that last modified the file.
+    pub modified_by: String,
+    pub deleted: bool,
+    pub invalid: bool,
+    #[serde(default)]
+    pub ignored: bool,
+    #[serde(default)]
+    pub must_rescan: bool,
+    #[serde(default)]
+    pub local_flags: u32,
+    pub sequence: i64,
+    pub version: VersionVector,
+    pub num_blocks: Option<u64>,
+    pub blocks_hash: Option<String>,
+}
+
+/// A page of files as returned by `/rest/db/remoteneed` and `/rest/db/localchanged`.
+#[derive(Debug, Deserialize)]
+pub struct FilePage {
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub files: Vec<FileInfo>,
+    pub page: u64,
+    pub perpage: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Availability {
+    #[serde(rename = "id")]
+    pub device_id: DeviceID,
+    pub from_temporary: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FileInfoResponse {
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub availability: Vec<Availability>,
+    pub global: FileInfo,
+    pub local: FileInfo,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    MissingLocally,
+    Ignored,
+    Invalid,
+    MustRescan,
+    LocalNewer,
+    GlobalNewer,
+    Conflict,
+    DeletedLocally,
+    DeletedGlobally,
+    Size,
+    Modified,
+    Permissions,
+    Content,
+    Unavailable,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingLocally => "the file does not exist in the local database",
+            Self::Ignored => "the file is ignored locally",
+            Self::Invalid => "the local version is invalid and will not be synced",
+            Self::MustRescan => "the local version must be rescanned",
+            Self::LocalNewer => "the local version is newer and has not been sent yet",
+            Self::GlobalNewer => "the global version is newer and has not been pulled yet",
+            Self::Conflict => "local and global version were changed concurrently",
+            Self::DeletedLocally => "the file was deleted locally but exists globally",
+            Self::DeletedGlobally => "the file was deleted globally but exists locally",
+            Self::Size => "local and global size differ",
+            Self::Modified => "local and global modification time differ",
+            Self::Permissions => "local and global permissions differ",
+            Self::Content => "local and global content differ",
+            Self::Unavailable => "no connected device has the global version",
+        })
+    }
+}
+
+impl FileInfoResponse {
+    /// Lists why the local version differs from the global one, empty if in sync.
+    pub fn explain(&self) -> Vec<Difference> {
+        let (local, global) = (&self.local, &self.global);
+        let mut differences = Vec::new();
+        if local.name.is_empty() {
+            // a deletion we never had a record of is in sync already
+            if !global.deleted {
+                differences.push(Difference::MissingLocally);
+                if self.availability.is_empty() {
+                    differences.push(Difference::Unavailable);
+                }
+            }
+            return differences;
+        }
+        if local.ignored {
+            differences.push(Difference::Ignored);
+        }
+        if local.invalid {
+            differences.push(Difference::Invalid);
+        }
+        if local.must_rescan {
+            differences.push(Difference::MustRescan);
+        }
+        match local.version.partial_cmp(&global.version) {
+            Some(Ordering::Equal) => return differences,
+            Some(Ordering::Greater) => differences.push(Difference::LocalNewer),
+            Some(Ordering::Less) => differences.push(Difference::GlobalNewer),
+            None => differences.push(Difference::Conflict),
+        }
+        match (local.deleted, global.deleted) {
+            (true, false) => differences.push(Difference::DeletedLocally),
+            (false, true) => differences.push(Difference::DeletedGlobally),
+            (true, true) => {}
+            (false, false) => {
+                if local.size != global.size {
+                    differences.push(Difference::Size);
+                }
+                if local.modified != global.modified {
+                    differences.push(Difference::Modified);
+                }
+                if local.permissions != global.permissions {
+                    differences.push(Difference::Permissions);
+                }
+                if local.blocks_hash != global.blocks_hash {
+                    differences.push(Difference::Content);
+                }
+            }
+        }
+        if self.availability.is_empty() && !global.deleted {
+            differences.push(Difference::Unavailable);
+        }
+        differences
+    }
+}
@@ -0,0 +1,18 @@
+use crate::utils;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct Ignores {
+    /// The raw lines of the `.stignore` file.
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub ignore: Vec<String>,
+    /// The patterns after includes were resolved.
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub expanded: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SetIgnores<'a> {
+    pub ignore: &'a [String],
+}
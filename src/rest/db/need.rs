@@ -0,0 +1,49 @@
+use crate::rest::db::file::FileInfo;
+use crate::utils;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Need {
+    /// Files currently being downloaded.
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub progress: Vec<FileInfo>,
+    /// Files queued for download next.
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub queued: Vec<FileInfo>,
+    /// Remaining files.
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub rest: Vec<FileInfo>,
+    pub page: u64,
+    pub perpage: u64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NeedCategory {
+    Progress,
+    Queued,
+    Rest,
+}
+
+#[derive(Debug)]
+pub struct NeedItem {
+    pub category: NeedCategory,
+    pub file: FileInfo,
+}
+
+impl Need {
+    pub fn into_items(self) -> Vec<NeedItem> {
+        let progress = self.progress.into_iter().map(|file| NeedItem {
+            category: NeedCategory::Progress,
+            file,
+        });
+        let queued = self.queued.into_iter().map(|file| NeedItem {
+            category: NeedCategory::Queued,
+            file,
+        });
+        let rest = self.rest.into_iter().map(|file| NeedItem {
+            category: NeedCategory::Rest,
+            file,
+        });
+        progress.chain(queued).chain(rest).collect()
+    }
+}
@@ -0,0 +1,34 @@
+use crate::rest::db::file::FileInfo;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PreviewAction {
+    /// Overwrite the remote changes of a send only folder.
+    Override,
+    /// Revert the local changes of a receive only folder.
+    Revert,
+}
+
+/// The files an override or revert would touch. Pass it to
+/// [`Client::confirm_preview`](crate::Client::confirm_preview) to carry out the action.
+#[derive(Debug)]
+pub struct Preview {
+    pub(crate) folder: String,
+    pub(crate) action: PreviewAction,
+    pub(crate) items: Vec<FileInfo>,
+}
+
+impl Preview {
+    pub fn folder(&self) -> &str {
+        &self.folder
+    }
+
+    pub fn action(&self) -> PreviewAction {
+        self.action
+    }
+
+    /// The global versions an override discards or the local versions a
+    /// revert reverts.
+    pub fn items(&self) -> &[FileInfo] {
+        &self.items
+    }
+}
@@ -0,0 +1,12 @@
+use crate::rest::events::FolderScanProgressEvent;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct ScanReport {
+    /// Time the folder spent scanning.
+    pub duration: Duration,
+    /// Errors the folder reported while scanning.
+    pub errors: Vec<String>,
+    /// The last progress reported, syncthing only reports progress of longer scans.
+    pub progress: Option<FolderScanProgressEvent>,
+}
@@ -0,0 +1,6 @@
+//! The debug endpoints are only served with debugging enabled in the GUI settings.
+
+pub mod download;
+pub mod file;
+pub mod http_metrics;
+pub mod peer_completion;
@@ -0,0 +1,41 @@
+use http::header::{HeaderMap, CONTENT_DISPOSITION, CONTENT_LENGTH};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Dump {
+    /// A pprof CPU profile.
+    CpuProfile,
+    /// A pprof heap profile.
+    HeapProfile,
+    /// A zip archive of the configuration, logs and usage data.
+    SupportBundle,
+}
+
+#[derive(Debug)]
+pub struct Download {
+    pub content_length: Option<u64>,
+    /// The file name syncthing suggests.
+    pub filename: Option<String>,
+}
+
+impl Download {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        let content_length = headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        let filename = headers
+            .get(CONTENT_DISPOSITION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| {
+                value
+                    .split(';')
+                    .filter_map(|param| param.trim().strip_prefix("filename="))
+                    .map(|filename| filename.trim_matches('"').to_owned())
+                    .next()
+            });
+        Self {
+            content_length,
+            filename,
+        }
+    }
+}
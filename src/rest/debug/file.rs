@@ -0,0 +1,17 @@
+use crate::rest::db::file::FileInfo;
+use crate::rest::DeviceID;
+use crate::utils;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct DebugFile {
+    pub global: FileInfo,
+    pub local: FileInfo,
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub availability: Vec<DeviceID>,
+    /// Syncthing's debug text dump of the global version list, i.e. each
+    /// version with the devices having it. The format is not stable and meant
+    /// for humans, use [`FileInfo::version`] for comparisons.
+    pub global_versions: String,
+}
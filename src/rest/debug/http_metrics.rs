@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Timer {
+    pub count: u64,
+    pub sum_ms: f64,
+    /// Requests per second over the last 1, 5 and 15 minutes.
+    pub rates_per_s: [f64; 3],
+    /// The 50th, 95th and 99th percentile of the latency.
+    pub percentiles_ms: [f64; 3],
+}
+
+/// Request metrics keyed by endpoint, e.g. `/rest/system/status`.
+pub type HttpMetrics = HashMap<String, Timer>;
@@ -0,0 +1,5 @@
+use crate::rest::DeviceID;
+use std::collections::HashMap;
+
+/// Completion percentage of every connected device over all shared folders.
+pub type PeerCompletion = HashMap<DeviceID, f64>;
@@ -1,5 +1,7 @@
+use crate::rest::db::completion::RemoteState;
 use crate::rest::DeviceID;
 use crate::rest::{FileName, Folder, FolderName};
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use serde_json::value::RawValue;
 use std::collections::HashMap;
@@ -70,6 +72,7 @@ pub struct FolderCompletionEvent {
     pub need_bytes: u64,
     pub need_deletes: u64,
     pub need_items: u64,
+    pub remote_state: RemoteState,
 }
 
 #[derive(Debug, Deserialize)]
@@ -136,8 +139,8 @@ pub struct FolderSummaryData {
     pub need_total_items: u64,
     pub pull_errors: u64,
     pub sequence: u64,
-    pub state: String,
-    pub state_changed: String, //FIXME: use enum
+    pub state: FolderState,
+    pub state_changed: DateTime<FixedOffset>,
     pub version: u64,
 }
 
@@ -177,7 +180,7 @@ pub struct LocalChangeDetectedEvent {
     #[serde(rename = "folderID")]
     pub folder_id: String,
     pub label: String,
-    path: String,
+    pub path: String,
     #[serde(rename = "type")]
     pub item_type: String, //FIXME: use enum
 }
@@ -234,7 +237,7 @@ pub struct StartingEvent {
     pub home: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub enum FolderState {
     Idle,
@@ -243,6 +246,8 @@ pub enum FolderState {
     SyncPreparing,
     SyncWaiting,
     Syncing,
+    Cleaning,
+    CleanWaiting,
     Error,
     Unknown,
 }
@@ -284,6 +289,9 @@ pub enum EventData {
     Starting(StartingEvent),
     StartupComplete,
     StateChanged(StateChangedEvent),
+    /// An event type this crate does not know, e.g. one added by a newer
+    /// syncthing, with its type name.
+    Unknown(String),
 }
 
 #[derive(Debug, Deserialize)]
@@ -292,11 +300,18 @@ pub(super) struct RawEvent {
     #[serde(rename = "globalID")]
     pub global_id: u64,
     #[serde(rename = "type")]
-    pub event_type: EventType,
+    pub event_type: RawEventType,
     pub time: String,
     pub data: Box<RawValue>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum RawEventType {
+    Known(EventType),
+    Unknown(String),
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
 pub enum EventType {
     ConfigSaved,
@@ -348,6 +363,17 @@ impl core::convert::TryFrom<RawEvent> for Event {
             data,
         } = raw_event;
         let data = data.get();
+        let event_type = match event_type {
+            RawEventType::Known(event_type) => event_type,
+            RawEventType::Unknown(event_type) => {
+                return Ok(Event {
+                    id,
+                    global_id,
+                    time,
+                    data: Unknown(event_type),
+                })
+            }
+        };
         Ok(Event {
             id,
             global_id,
@@ -0,0 +1,12 @@
+use crate::rest::events::FolderError;
+use crate::utils;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct FolderErrors {
+    pub folder: String,
+    #[serde(deserialize_with = "utils::default_on_null")]
+    pub errors: Vec<FolderError>,
+    pub page: u64,
+    pub perpage: u64,
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct FileVersion {
+    /// When the version was archived.
+    pub version_time: DateTime<FixedOffset>,
+    pub mod_time: DateTime<FixedOffset>,
+    pub size: u64,
+}
+
+pub type Versions = HashMap<String, Vec<FileVersion>>;
+
+/// The error message for every path that could not be restored.
+pub type RestoreErrors = HashMap<String, String>;
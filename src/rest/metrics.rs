@@ -0,0 +1,359 @@
+use anyhow::{anyhow, bail, Error};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+pub type Labels = BTreeMap<String, String>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+    Untyped,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket {
+    pub upper_bound: f64,
+    /// Number of observations less than or equal to `upper_bound`.
+    pub count: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantile {
+    pub quantile: f64,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Counter(f64),
+    Gauge(f64),
+    Histogram {
+        buckets: Vec<Bucket>,
+        sum: f64,
+        count: f64,
+    },
+    Summary {
+        quantiles: Vec<Quantile>,
+        sum: f64,
+        count: f64,
+    },
+    Untyped(f64),
+}
+
+impl Value {
+    /// The value of a counter, gauge or untyped metric.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Counter(value) | Self::Gauge(value) | Self::Untyped(value) => Some(*value),
+            Self::Histogram { .. } | Self::Summary { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metric {
+    pub labels: Labels,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricFamily {
+    pub name: String,
+    pub help: String,
+    pub metric_type: MetricType,
+    pub metrics: Vec<Metric>,
+}
+
+impl MetricFamily {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            help: String::new(),
+            metric_type: MetricType::Untyped,
+            metrics: Vec::new(),
+        }
+    }
+
+    fn metric_mut(&mut self, labels: Labels) -> &mut Metric {
+        let position = match self
+            .metrics
+            .iter()
+            .position(|metric| metric.labels == labels)
+        {
+            Some(position) => position,
+            None => {
+                let value = match self.metric_type {
+                    MetricType::Counter => Value::Counter(0.0),
+                    MetricType::Gauge => Value::Gauge(0.0),
+                    MetricType::Histogram => Value::Histogram {
+                        buckets: Vec::new(),
+                        sum: 0.0,
+                        count: 0.0,
+                    },
+                    MetricType::Summary => Value::Summary {
+                        quantiles: Vec::new(),
+                        sum: 0.0,
+                        count: 0.0,
+                    },
+                    MetricType::Untyped => Value::Untyped(0.0),
+                };
+                self.metrics.push(Metric { labels, value });
+                self.metrics.len() - 1
+            }
+        };
+        &mut self.metrics[position]
+    }
+
+    fn add_sample(&mut self, name: &str, mut labels: Labels, sample: f64) -> Result<(), Error> {
+        let suffix = &name[self.name.len()..];
+        let bound = match (self.metric_type, suffix) {
+            (MetricType::Histogram, "_bucket") => Some(labels.remove("le")),
+            (MetricType::Summary, "") => Some(labels.remove("quantile")),
+            _ => None,
+        };
+        let bound = match bound {
+            Some(Some(bound)) => Some(parse_float(&bound)?),
+            Some(None) => bail!("sample '{}' is missing its bound label", name),
+            None => None,
+        };
+        match (&mut self.metric_mut(labels).value, suffix, bound) {
+            (Value::Counter(value), "", _)
+            | (Value::Gauge(value), "", _)
+            | (Value::Untyped(value), "", _) => *value = sample,
+            (Value::Histogram { buckets, .. }, "_bucket", Some(upper_bound)) => {
+                buckets.push(Bucket {
+                    upper_bound,
+                    count: sample,
+                })
+            }
+            (Value::Summary { quantiles, .. }, "", Some(quantile)) => quantiles.push(Quantile {
+                quantile,
+                value: sample,
+            }),
+            (Value::Histogram { sum, .. }, "_sum", _) | (Value::Summary { sum, .. }, "_sum", _) => {
+                *sum = sample
+            }
+            (Value::Histogram { count, .. }, "_count", _)
+            | (Value::Summary { count, .. }, "_count", _) => *count = sample,
+            _ => bail!("unexpected sample '{}' in family '{}'", name, self.name),
+        }
+        Ok(())
+    }
+}
+
+/// Well-known syncthing series.
+pub mod series {
+    pub static FOLDER_STATE: &str = "syncthing_model_folder_state";
+    pub static FOLDER_PULL_SECONDS: &str = "syncthing_model_folder_pull_seconds_total";
+    pub static FOLDER_SCAN_SECONDS: &str = "syncthing_model_folder_scan_seconds_total";
+    pub static FOLDER_PROCESSED_BYTES: &str = "syncthing_model_folder_processed_bytes_total";
+    pub static SCANNER_HASHED_BYTES: &str = "syncthing_scanner_hashed_bytes_total";
+    pub static SCANNER_SCANNED_ITEMS: &str = "syncthing_scanner_scanned_items_total";
+    pub static PROTOCOL_SENT_BYTES: &str = "syncthing_protocol_sent_bytes_total";
+    pub static PROTOCOL_RECV_BYTES: &str = "syncthing_protocol_recv_bytes_total";
+}
+
+/// Metrics parsed from the Prometheus text exposition format.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metrics {
+    pub families: Vec<MetricFamily>,
+}
+
+impl Metrics {
+    pub fn family(&self, name: &str) -> Option<&MetricFamily> {
+        self.families.iter().find(|family| family.name == name)
+    }
+
+    /// Sums the counter, gauge or untyped metrics of family `name` whose
+    /// labels include all of `labels`.
+    pub fn value(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        let mut values = self
+            .family(name)?
+            .metrics
+            .iter()
+            .filter(|metric| {
+                labels
+                    .iter()
+                    .all(|(key, value)| metric.labels.get(*key).map(String::as_str) == Some(*value))
+            })
+            .filter_map(|metric| metric.value.as_f64())
+            .peekable();
+        values.peek()?;
+        Some(values.sum())
+    }
+
+    /// The state of `folder` as the numeric value of syncthing's folder state.
+    pub fn folder_state(&self, folder: &str) -> Option<f64> {
+        self.value(series::FOLDER_STATE, &[("folder", folder)])
+    }
+
+    pub fn folder_pull_seconds(&self, folder: &str) -> Option<f64> {
+        self.value(series::FOLDER_PULL_SECONDS, &[("folder", folder)])
+    }
+
+    pub fn folder_scan_seconds(&self, folder: &str) -> Option<f64> {
+        self.value(series::FOLDER_SCAN_SECONDS, &[("folder", folder)])
+    }
+
+    /// Bytes processed by `folder` over all sources, e.g. network or local reuse.
+    pub fn folder_processed_bytes(&self, folder: &str) -> Option<f64> {
+        self.value(series::FOLDER_PROCESSED_BYTES, &[("folder", folder)])
+    }
+
+    pub fn scanner_hashed_bytes(&self, folder: &str) -> Option<f64> {
+        self.value(series::SCANNER_HASHED_BYTES, &[("folder", folder)])
+    }
+
+    pub fn scanner_scanned_items(&self, folder: &str) -> Option<f64> {
+        self.value(series::SCANNER_SCANNED_ITEMS, &[("folder", folder)])
+    }
+
+    pub fn sent_bytes(&self, device: &str) -> Option<f64> {
+        self.value(series::PROTOCOL_SENT_BYTES, &[("device", device)])
+    }
+
+    pub fn received_bytes(&self, device: &str) -> Option<f64> {
+        self.value(series::PROTOCOL_RECV_BYTES, &[("device", device)])
+    }
+
+    fn family_mut(&mut self, name: &str) -> &mut MetricFamily {
+        let position = match self.families.iter().position(|family| family.name == name) {
+            Some(position) => position,
+            None => {
+                self.families.push(MetricFamily::new(name));
+                self.families.len() - 1
+            }
+        };
+        &mut self.families[position]
+    }
+
+    fn family_of_sample(&mut self, name: &str) -> &mut MetricFamily {
+        let family = ["_bucket", "_sum", "_count"]
+            .iter()
+            .filter_map(|suffix| name.strip_suffix(suffix))
+            .find(|family| {
+                matches!(
+                    self.family(family).map(|family| family.metric_type),
+                    Some(MetricType::Histogram) | Some(MetricType::Summary)
+                )
+            })
+            .unwrap_or(name)
+            .to_owned();
+        self.family_mut(&family)
+    }
+}
+
+impl FromStr for Metrics {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut metrics = Self::default();
+        for (number, line) in s.lines().enumerate() {
+            parse_line(&mut metrics, line.trim())
+                .map_err(|err| anyhow!("line {}: {}", number + 1, err))?;
+        }
+        Ok(metrics)
+    }
+}
+
+fn parse_line(metrics: &mut Metrics, line: &str) -> Result<(), Error> {
+    if line.is_empty() {
+        return Ok(());
+    }
+    if let Some(comment) = line.strip_prefix('#') {
+        let mut parts = comment.trim_start().splitn(3, ' ');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("HELP"), Some(name), help) => {
+                metrics.family_mut(name).help = unescape(help.unwrap_or_default(), false);
+            }
+            (Some("TYPE"), Some(name), Some(metric_type)) => {
+                metrics.family_mut(name).metric_type = match metric_type.trim() {
+                    "counter" => MetricType::Counter,
+                    "gauge" => MetricType::Gauge,
+                    "histogram" => MetricType::Histogram,
+                    "summary" => MetricType::Summary,
+                    "untyped" => MetricType::Untyped,
+                    metric_type => bail!("unknown metric type '{}'", metric_type),
+                };
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+    let name_end = line.find(['{', ' ', '\t']).unwrap_or(line.len());
+    let name = &line[..name_end];
+    let mut rest = &line[name_end..];
+    let mut labels = Labels::new();
+    if rest.starts_with('{') {
+        let (parsed, remainder) = parse_labels(&rest[1..])?;
+        labels = parsed;
+        rest = remainder;
+    }
+    // an optional timestamp may follow the value
+    let value = rest
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| anyhow!("sample '{}' has no value", name))?;
+    let value = parse_float(value)?;
+    metrics
+        .family_of_sample(name)
+        .add_sample(name, labels, value)
+}
+
+fn parse_labels(mut s: &str) -> Result<(Labels, &str), Error> {
+    let mut labels = Labels::new();
+    loop {
+        s = s.trim_start_matches([' ', ',']);
+        if let Some(rest) = s.strip_prefix('}') {
+            return Ok((labels, rest));
+        }
+        let equals = s.find('=').ok_or_else(|| anyhow!("malformed labels"))?;
+        let key = s[..equals].trim().to_owned();
+        let quoted = s[equals + 1..]
+            .trim_start()
+            .strip_prefix('"')
+            .ok_or_else(|| anyhow!("label '{}' is not quoted", key))?;
+        let mut escaped = false;
+        let end = quoted
+            .char_indices()
+            .find(|&(_, c)| {
+                let end = c == '"' && !escaped;
+                escaped = c == '\\' && !escaped;
+                end
+            })
+            .map(|(end, _)| end)
+            .ok_or_else(|| anyhow!("label '{}' is not terminated", key))?;
+        labels.insert(key, unescape(&quoted[..end], true));
+        s = &quoted[end + 1..];
+    }
+}
+
+fn unescape(s: &str, quotes: bool) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('\\') => unescaped.push('\\'),
+            Some('"') if quotes => unescaped.push('"'),
+            Some(c) => {
+                unescaped.push('\\');
+                unescaped.push(c);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+fn parse_float(s: &str) -> Result<f64, Error> {
+    s.parse()
+        .map_err(|_| anyhow!("invalid sample value '{}'", s))
+}
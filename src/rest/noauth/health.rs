@@ -0,0 +1,31 @@
+use crate::rest::events::FolderError;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+pub enum Status {
+    #[serde(rename = "OK")]
+    Ok,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Health {
+    pub status: Status,
+}
+
+#[derive(Debug)]
+pub enum Readiness {
+    Ready,
+    /// The health check failed, syncthing is not running or not reachable.
+    Unhealthy(anyhow::Error),
+    /// Syncthing is running but the authenticated API could not be used.
+    Unavailable(anyhow::Error),
+    /// Syncthing is running but some folders report errors.
+    FolderErrors(HashMap<String, Vec<FolderError>>),
+}
+
+impl Readiness {
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready)
+    }
+}
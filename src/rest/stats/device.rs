@@ -0,0 +1,15 @@
+use crate::rest::DeviceID;
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct DeviceStatistics {
+    pub last_seen: DateTime<FixedOffset>,
+    /// In seconds.
+    #[serde(rename = "lastConnectionDurationS")]
+    pub last_connection_duration: f64,
+}
+
+pub type DeviceStats = HashMap<DeviceID, DeviceStatistics>;
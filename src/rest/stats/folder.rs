@@ -0,0 +1,19 @@
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+pub struct LastFile {
+    pub at: DateTime<FixedOffset>,
+    pub filename: String,
+    pub deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct FolderStatistics {
+    pub last_file: LastFile,
+    pub last_scan: DateTime<FixedOffset>,
+}
+
+pub type FolderStats = HashMap<String, FolderStatistics>;
@@ -0,0 +1,4 @@
+pub mod device_id;
+pub mod lang;
+pub mod random;
+pub mod report;
@@ -0,0 +1,14 @@
+use crate::rest::DeviceID;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DeviceIdValidation {
+    /// The device id in its normalized form.
+    Valid {
+        id: DeviceID,
+    },
+    Invalid {
+        error: String,
+    },
+}
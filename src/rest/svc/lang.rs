@@ -0,0 +1,2 @@
+/// Language codes in order of the browser's preference, e.g. `en-US`.
+pub type Languages = Vec<String>;
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Report {
+    pub ur_version: u64,
+    #[serde(rename = "uniqueID")]
+    pub unique_id: String,
+    pub version: String,
+    pub long_version: String,
+    pub platform: String,
+    #[serde(rename = "numCPU")]
+    pub num_cpu: u64,
+    pub memory_size: u64,
+    #[serde(rename = "memoryUsageMiB")]
+    pub memory_usage_mib: u64,
+    pub sha256_perf: f64,
+    pub hash_perf: f64,
+    pub num_folders: u64,
+    pub num_devices: u64,
+    pub tot_files: u64,
+    pub folder_max_files: u64,
+    #[serde(rename = "totMiB")]
+    pub tot_mib: u64,
+    #[serde(rename = "folderMaxMiB")]
+    pub folder_max_mib: u64,
+    /// Number of folders using a feature, e.g. `sendonly`, and since version 3
+    /// also breakdowns like `pullOrder` or per folder lists like `fsWatcherDelays`.
+    pub folder_uses: HashMap<String, Value>,
+    /// Number of devices using a feature, e.g. `introducer` or `dynamicAddr`.
+    pub device_uses: HashMap<String, u64>,
+    /// Every other field of the report, depending on `ur_version`.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
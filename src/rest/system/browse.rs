@@ -0,0 +1,3 @@
+use std::path::PathBuf;
+
+pub type Browse = Vec<PathBuf>;
@@ -1,9 +1,97 @@
 use crate::rest::DeviceID;
-use serde::Deserialize;
+use anyhow::{anyhow, bail, Error};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
-//FIXME: verify that this is the device id
-pub type Addr = String;
+/// A device address in URL form, e.g. `tcp://192.0.2.1:22000` or
+/// `relay://192.0.2.1:22067/?id=...`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Addr {
+    pub scheme: String,
+    /// IPv6 hosts are stored without brackets.
+    pub host: String,
+    pub port: Option<u16>,
+    /// Everything after `host:port`, e.g. the query of relay addresses.
+    pub rest: String,
+}
+
+impl FromStr for Addr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (scheme, remainder) = s
+            .split_once("://")
+            .ok_or_else(|| anyhow!("address '{}' has no scheme", s))?;
+        let authority_end = remainder.find(['/', '?']).unwrap_or(remainder.len());
+        let (authority, rest) = remainder.split_at(authority_end);
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, port) = bracketed
+                    .split_once(']')
+                    .ok_or_else(|| anyhow!("address '{}' has an unterminated host", s))?;
+                match port {
+                    "" => (host, None),
+                    port => match port.strip_prefix(':') {
+                        Some(port) => (host, Some(port)),
+                        None => bail!("address '{}' has a malformed port", s),
+                    },
+                }
+            }
+            None => match authority.rsplit_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            },
+        };
+        if scheme.is_empty() || host.is_empty() {
+            bail!("address '{}' has no scheme or host", s);
+        }
+        let port = port
+            .map(|port| {
+                port.parse()
+                    .map_err(|_| anyhow!("address '{}' has an invalid port", s))
+            })
+            .transpose()?;
+        Ok(Self {
+            scheme: scheme.to_owned(),
+            host: host.to_owned(),
+            port,
+            rest: rest.to_owned(),
+        })
+    }
+}
+
+impl TryFrom<String> for Addr {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Addr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://", self.scheme)?;
+        if self.host.contains(':') {
+            write!(f, "[{}]", self.host)?;
+        } else {
+            f.write_str(&self.host)?;
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        f.write_str(&self.rest)
+    }
+}
+
+impl From<Addr> for String {
+    fn from(addr: Addr) -> Self {
+        addr.to_string()
+    }
+}
 
 #[derive(Debug, Deserialize)]
 pub struct Addresses {
@@ -11,3 +99,47 @@ pub struct Addresses {
 }
 
 pub type Discovery = HashMap<DeviceID, Addresses>;
+
+/// Static device addresses, one device per line followed by its addresses:
+///
+/// ```text
+/// # comments and empty lines are ignored
+/// MFZWI3D-BONSGYC-YLTMRWG-C43ENR5-QXGZDMM-FZWI3DP-BONSGYY-LTMRWAD tcp://192.0.2.1:22000
+/// ```
+#[derive(Debug, Default)]
+pub struct Inventory {
+    pub devices: HashMap<DeviceID, Vec<Addr>>,
+}
+
+impl FromStr for Inventory {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut inventory = Self::default();
+        for (number, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let device_id = fields.next().unwrap().to_owned();
+            let addrs = fields
+                .map(str::parse)
+                .collect::<Result<Vec<Addr>, _>>()
+                .map_err(|err| anyhow!("line {}: {}", number + 1, err))?;
+            if addrs.is_empty() {
+                return Err(anyhow!(
+                    "line {}: no addresses for device '{}'",
+                    number + 1,
+                    device_id
+                ));
+            }
+            inventory
+                .devices
+                .entry(device_id)
+                .or_default()
+                .extend(addrs);
+        }
+        Ok(inventory)
+    }
+}
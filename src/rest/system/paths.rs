@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Paths {
+    pub config: PathBuf,
+    pub database: PathBuf,
+    pub cert_file: PathBuf,
+    pub key_file: PathBuf,
+    pub https_cert_file: PathBuf,
+    pub https_key_file: PathBuf,
+    pub log_file: PathBuf,
+    /// Remaining locations such as `baseDir-config` or `panicLog`.
+    #[serde(flatten)]
+    pub other: HashMap<String, PathBuf>,
+}
@@ -1,9 +1,45 @@
+pub static CLUSTER_PENDING_DEVICES_PATH: &str = "/rest/cluster/pending/devices";
+pub static CLUSTER_PENDING_FOLDERS_PATH: &str = "/rest/cluster/pending/folders";
+pub static CONFIG_DEVICES_PATH: &str = "/rest/config/devices";
+pub static CONFIG_FOLDERS_PATH: &str = "/rest/config/folders";
+pub static DB_BROWSE_PATH: &str = "/rest/db/browse";
+pub static DB_COMPLETION_PATH: &str = "/rest/db/completion";
+pub static DB_FILE_PATH: &str = "/rest/db/file";
+pub static DB_IGNORES_PATH: &str = "/rest/db/ignores";
+pub static DB_LOCAL_CHANGED_PATH: &str = "/rest/db/localchanged";
+pub static DB_NEED_PATH: &str = "/rest/db/need";
+pub static DB_OVERRIDE_PATH: &str = "/rest/db/override";
+pub static DB_PRIO_PATH: &str = "/rest/db/prio";
+pub static DB_REMOTE_NEED_PATH: &str = "/rest/db/remoteneed";
+pub static DB_REVERT_PATH: &str = "/rest/db/revert";
+pub static DB_SCAN_PATH: &str = "/rest/db/scan";
+pub static DB_STATUS_PATH: &str = "/rest/db/status";
+pub static DEBUG_CPU_PROF_PATH: &str = "/rest/debug/cpuprof";
+pub static DEBUG_FILE_PATH: &str = "/rest/debug/file";
+pub static DEBUG_HEAP_PROF_PATH: &str = "/rest/debug/heapprof";
+pub static DEBUG_HTTP_METRICS_PATH: &str = "/rest/debug/httpmetrics";
+pub static DEBUG_PEER_COMPLETION_PATH: &str = "/rest/debug/peerCompletion";
+pub static DEBUG_SUPPORT_PATH: &str = "/rest/debug/support";
 pub static EVENTS_PATH: &str = "/rest/events";
+pub static EVENTS_DISK_PATH: &str = "/rest/events/disk";
+pub static FOLDER_ERRORS_PATH: &str = "/rest/folder/errors";
+pub static FOLDER_VERSIONS_PATH: &str = "/rest/folder/versions";
+pub static METRICS_PATH: &str = "/metrics";
+pub static NOAUTH_HEALTH_PATH: &str = "/rest/noauth/health";
+pub static STATS_DEVICE_PATH: &str = "/rest/stats/device";
+pub static STATS_FOLDER_PATH: &str = "/rest/stats/folder";
+pub static SVC_DEVICE_ID_PATH: &str = "/rest/svc/deviceid";
+pub static SVC_LANG_PATH: &str = "/rest/svc/lang";
+pub static SVC_RANDOM_STRING_PATH: &str = "/rest/svc/random/string";
+pub static SVC_REPORT_PATH: &str = "/rest/svc/report";
+pub static SYSTEM_BROWSE_PATH: &str = "/rest/system/browse";
 pub static SYSTEM_CONNECTIONS_PATH: &str = "/rest/system/connections";
 pub static SYSTEM_DEBUG_PATH: &str = "/rest/system/debug";
 pub static SYSTEM_DISCOVERY_PATH: &str = "/rest/system/discovery";
 pub static SYSTEM_LOG_PATH: &str = "/rest/system/log";
 pub static SYSTEM_ERROR_PATH: &str = "/rest/system/error";
+pub static SYSTEM_ERROR_CLEAR_PATH: &str = "/rest/system/error/clear";
+pub static SYSTEM_PATHS_PATH: &str = "/rest/system/paths";
 pub static SYSTEM_PING_PATH: &str = "/rest/system/ping";
 pub static SYSTEM_UPGRADE_PATH: &str = "/rest/system/upgrade";
 pub static SYSTEM_VERSION_PATH: &str = "/rest/system/version";
@@ -1,8 +1,493 @@
+use crate::rest::db::file::{Difference, FileInfoResponse, VersionVector};
+use crate::rest::debug::download::Dump;
+use crate::rest::events::{Event, EventData};
+use crate::rest::metrics::{MetricType, Metrics, Value};
+use crate::rest::noauth::health::Readiness;
+use crate::rest::svc::device_id::DeviceIdValidation;
+use crate::rest::svc::report::Report;
+use crate::rest::system::discovery::Inventory;
 use crate::{Client, Fallible};
 use futures_util::stream::StreamExt;
+use std::cmp::Ordering;
+use std::num::NonZeroU64;
+use std::time::Duration;
 
 static API_KEY: &str = include_str!("../api.key");
 
+#[tokio::test]
+async fn browse_system_path() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.browse_system_path("/").await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_pending_devices() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_pending_devices().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_pending_folders() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_pending_folders(None).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_config_folders() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_config_folders().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn browse_db() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        client.browse_db(&folder.id, None, Some(1)).await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn walk_db() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        let mut stream = client.clone().walk_db(folder.id, "", Some(1));
+        while let Some(entry) = stream.next().await {
+            assert!(entry?.depth <= 1);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_db_completion() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_db_completion(None, None).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_db_completions() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_db_completions().await?;
+    Ok(())
+}
+
+fn file_info(name: &str, version: &[&str], deleted: bool, size: u64) -> serde_json::Value {
+    serde_json::json!({
+        "name": name,
+        "type": "FILE_INFO_TYPE_FILE",
+        "size": size,
+        "permissions": "0644",
+        "noPermissions": false,
+        "modified": "2020-06-04T15:47:52+02:00",
+        "modifiedBy": "ABCDEFG",
+        "deleted": deleted,
+        "invalid": false,
+        "sequence": 1,
+        "version": version,
+        "numBlocks": 1,
+        "blocksHash": format!("hash-{}", size),
+    })
+}
+
+fn file_info_response(
+    local: serde_json::Value,
+    global: serde_json::Value,
+    available: bool,
+) -> Fallible<FileInfoResponse> {
+    let availability = if available {
+        serde_json::json!([{ "id": "ABCDEFG", "fromTemporary": false }])
+    } else {
+        serde_json::Value::Null
+    };
+    Ok(serde_json::from_value(serde_json::json!({
+        "availability": availability,
+        "local": local,
+        "global": global,
+    }))?)
+}
+
+#[test]
+fn version_vector_ordering() -> Fallible<()> {
+    let vector = |counters: &[&str]| serde_json::from_value::<VersionVector>(counters.into());
+    let (a1, a2) = (vector(&["A:1"])?, vector(&["A:2"])?);
+    let (a1b1, b1) = (vector(&["A:1", "B:1"])?, vector(&["B:1"])?);
+    assert_eq!(a1.partial_cmp(&vector(&["A:1"])?), Some(Ordering::Equal));
+    assert_eq!(a1.partial_cmp(&a2), Some(Ordering::Less));
+    assert_eq!(a2.partial_cmp(&a1), Some(Ordering::Greater));
+    assert_eq!(a1b1.partial_cmp(&a1), Some(Ordering::Greater));
+    assert_eq!(vector(&[])?.partial_cmp(&a1), Some(Ordering::Less));
+    assert_eq!(a2.partial_cmp(&a1b1), None);
+    assert_eq!(a1.partial_cmp(&b1), None);
+    Ok(())
+}
+
+#[test]
+fn explain_file_differences() -> Fallible<()> {
+    let explain = |local, global, available| -> Fallible<Vec<Difference>> {
+        Ok(file_info_response(local, global, available)?.explain())
+    };
+    let file = |version: &[&str], deleted, size| file_info("a", version, deleted, size);
+
+    assert!(explain(file(&["A:1"], false, 1), file(&["A:1"], false, 1), true)?.is_empty());
+    assert_eq!(
+        explain(file(&["A:1"], false, 1), file(&["A:2"], false, 2), true)?,
+        [
+            Difference::GlobalNewer,
+            Difference::Size,
+            Difference::Content
+        ]
+    );
+    assert_eq!(
+        explain(file(&["A:2"], false, 1), file(&["A:1"], false, 1), true)?,
+        [Difference::LocalNewer]
+    );
+    assert_eq!(
+        explain(
+            file(&["A:2"], false, 1),
+            file(&["A:1", "B:1"], false, 1),
+            true
+        )?,
+        [Difference::Conflict]
+    );
+    assert_eq!(
+        explain(file(&["A:2"], true, 0), file(&["A:1"], false, 1), true)?,
+        [Difference::LocalNewer, Difference::DeletedLocally]
+    );
+    assert_eq!(
+        explain(file(&["A:1"], false, 1), file(&["A:2"], true, 0), false)?,
+        [Difference::GlobalNewer, Difference::DeletedGlobally]
+    );
+    assert_eq!(
+        explain(file(&["A:1"], false, 1), file(&["A:2"], false, 1), false)?,
+        [Difference::GlobalNewer, Difference::Unavailable]
+    );
+    let missing = file_info("", &[], false, 0);
+    assert_eq!(
+        explain(missing.clone(), file(&["A:1"], false, 1), true)?,
+        [Difference::MissingLocally]
+    );
+    assert_eq!(
+        explain(missing.clone(), file(&["A:1"], false, 1), false)?,
+        [Difference::MissingLocally, Difference::Unavailable]
+    );
+    assert!(explain(missing, file(&["A:1"], true, 0), false)?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_db_file() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        for entry in client.browse_db(&folder.id, None, Some(0)).await? {
+            client.get_db_file(&folder.id, &entry.name).await?.explain();
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_and_set_ignores() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        let ignores = client.get_ignores(&folder.id).await?;
+        let changed = client
+            .apply_ignore_template(&[&folder.id], &ignores.ignore)
+            .await?;
+        assert!(changed.is_empty());
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_db_need() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        client.get_db_need(&folder.id, Some(1), Some(10)).await?;
+        let mut stream = client
+            .clone()
+            .stream_db_need(folder.id, NonZeroU64::new(10).unwrap());
+        while let Some(item) = stream.next().await {
+            item?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn prioritize_file() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        let need = client.get_db_need(&folder.id, None, None).await?;
+        if let Some(item) = need.into_items().pop() {
+            client.prioritize_file(&folder.id, &item.file.name).await?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn prioritize_unneeded_file_and_wait() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        assert!(client
+            .prioritize_file_and_wait(&folder.id, "syncthing-rs/not-needed")
+            .await
+            .is_err());
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_db_remote_need() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    let connections = client.get_system_connections().await?.connections;
+    for folder in client.get_config_folders().await? {
+        for device in folder.devices {
+            if !connections.contains_key(&device.device_id) {
+                continue;
+            }
+            let mut stream = client.clone().stream_db_remote_need(
+                &folder.id,
+                device.device_id,
+                NonZeroU64::new(10).unwrap(),
+            );
+            while let Some(file) = stream.next().await {
+                file?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_db_local_changed() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        let mut stream = client
+            .clone()
+            .stream_db_local_changed(folder.id, NonZeroU64::new(10).unwrap());
+        while let Some(file) = stream.next().await {
+            file?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn preview_override_and_revert() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        let preview = client.preview_override(&folder.id).await?;
+        assert!(client
+            .confirm_preview(&preview, preview.items().len() + 1)
+            .await
+            .is_err());
+        client.preview_revert(&folder.id).await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn scan_and_wait() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        if !folder.paused {
+            client
+                .scan_and_wait(&folder.id, Vec::<String>::new(), None)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_db_status() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        client.get_db_status(&folder.id).await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn get_debug_peer_completion() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_debug_peer_completion().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn get_debug_http_metrics() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_debug_http_metrics().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn get_debug_file() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        for entry in client.browse_db(&folder.id, None, Some(0)).await? {
+            client.get_debug_file(&folder.id, &entry.name).await?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn download_debug() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    let mut bundle = Vec::new();
+    let download = client
+        .download_debug(Dump::SupportBundle, &mut bundle)
+        .await?;
+    assert_eq!(download.content_length, Some(bundle.len() as u64));
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_folder_errors() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        client
+            .get_folder_errors(&folder.id, Some(1), Some(10))
+            .await?;
+        let mut stream = client
+            .clone()
+            .stream_folder_errors(folder.id, NonZeroU64::new(10).unwrap());
+        while let Some(error) = stream.next().await {
+            error?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_folder_versions() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    for folder in client.get_config_folders().await? {
+        if !folder.versioning.versioning_type.is_empty() {
+            client.get_folder_versions(&folder.id).await?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_stats_device() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_stats_device().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_stats_folder() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_stats_folder().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn validate_device_id() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    assert!(matches!(
+        client.validate_device_id("invalid").await?,
+        DeviceIdValidation::Invalid { .. }
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_languages() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_languages().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn random_string() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    assert_eq!(client.random_string(32).await?.len(), 32);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_usage_report() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_usage_report().await?;
+    Ok(())
+}
+
+#[test]
+fn parse_usage_report() -> Fallible<()> {
+    let report: Report = serde_json::from_str(
+        r#"{
+            "urVersion": 3,
+            "uniqueID": "6vulmdGw",
+            "version": "v1.12.0",
+            "longVersion": "syncthing v1.12.0 \"Fermium Flea\" (go1.15.5 linux-amd64)",
+            "platform": "linux-amd64",
+            "numFolders": 2,
+            "numDevices": 3,
+            "totFiles": 3804,
+            "folderMaxFiles": 3800,
+            "totMiB": 5062,
+            "folderMaxMiB": 5060,
+            "memoryUsageMiB": 45,
+            "sha256Perf": 1015.36,
+            "hashPerf": 950.12,
+            "memorySize": 15893,
+            "numCPU": 8,
+            "folderUses": {
+                "sendonly": 0,
+                "sendreceive": 2,
+                "receiveonly": 0,
+                "ignorePerms": 0,
+                "autoNormalize": 2,
+                "staggeredVersioning": 1,
+                "fsWatcherEnabled": 2,
+                "pullOrder": {"random": 2},
+                "filesystemType": {"basic": 2},
+                "fsWatcherDelays": [10, 10],
+                "modTimeWindowS": [0, 0],
+                "maxConcurrentWrites": [2, 2],
+                "blockPullOrder": {"standard": 2},
+                "copyRangeMethod": {"standard": 2},
+                "caseSensitiveFS": 0
+            },
+            "deviceUses": {
+                "introducer": 0,
+                "customCertName": 0,
+                "compressMetadata": 3,
+                "dynamicAddr": 3,
+                "staticAddr": 0
+            },
+            "announce": {"globalEnabled": true, "localEnabled": true},
+            "relays": {"enabled": true, "defaultServers": 1, "otherServers": 0},
+            "usesRateLimit": false,
+            "transportStats": {"tcp4": 2},
+            "uptime": 1234,
+            "natType": "Port restricted NAT"
+        }"#,
+    )?;
+    assert_eq!(report.ur_version, 3);
+    assert_eq!(report.folder_uses["sendreceive"], 2);
+    assert_eq!(report.folder_uses["pullOrder"]["random"], 2);
+    assert_eq!(report.device_uses["dynamicAddr"], 3);
+    assert_eq!(report.other["uptime"], 1234);
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_system_connections() -> Fallible<()> {
     let client = Client::new(API_KEY);
@@ -24,6 +509,51 @@ async fn get_system_discovery() -> Fallible<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore]
+async fn post_system_discovery() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client
+        .post_system_discovery(
+            "MFZWI3D-BONSGYC-YLTMRWG-C43ENR5-QXGZDMM-FZWI3DP-BONSGYY-LTMRWAD",
+            "tcp://192.0.2.1:22000",
+        )
+        .await?;
+    Ok(())
+}
+
+#[test]
+fn parse_discovery_inventory() -> Fallible<()> {
+    let inventory: Inventory = "
+        # static addresses
+
+        AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA tcp://192.0.2.1:22000
+        BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB quic://[2001:db8::1]:22000
+        AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA relay://192.0.2.2:22067/?id=x
+    "
+    .parse()?;
+    assert_eq!(inventory.devices.len(), 2);
+    let addrs =
+        &inventory.devices["AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA"];
+    assert_eq!(addrs.len(), 2);
+    assert_eq!(addrs[0].scheme, "tcp");
+    assert_eq!(addrs[0].host, "192.0.2.1");
+    assert_eq!(addrs[0].port, Some(22000));
+    assert_eq!(addrs[1].rest, "/?id=x");
+    assert_eq!(addrs[1].to_string(), "relay://192.0.2.2:22067/?id=x");
+    let addr =
+        &inventory.devices["BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB-BBBBBBB"][0];
+    assert_eq!(addr.host, "2001:db8::1");
+    assert_eq!(addr.to_string(), "quic://[2001:db8::1]:22000");
+
+    let err = "# comment\nAAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA-AAAAAAA\n"
+        .parse::<Inventory>()
+        .unwrap_err();
+    assert!(err.to_string().starts_with("line 2: no addresses"));
+    assert!("AAAAAAA tcp://192.0.2.1:port".parse::<Inventory>().is_err());
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_system_log() -> Fallible<()> {
     let client = Client::new(API_KEY);
@@ -31,6 +561,13 @@ async fn get_system_log() -> Fallible<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_system_paths() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_system_paths().await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_system_ping() -> Fallible<()> {
     let client = Client::new(API_KEY);
@@ -45,6 +582,33 @@ async fn get_system_error() -> Fallible<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn post_and_clear_system_errors() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.post_system_error("syncthing-rs test error").await?;
+    client.clear_system_errors().await?;
+    assert!(client.get_system_error().await?.errors.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn system_error_stream() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.clear_system_errors().await?;
+    client.post_system_error("syncthing-rs old error").await?;
+    let mut stream = client
+        .clone()
+        .subscribe_to_system_errors(Duration::from_secs(1));
+    let post = async {
+        tokio::time::delay_for(Duration::from_millis(500)).await;
+        client.post_system_error("syncthing-rs new error").await
+    };
+    let (entry, posted) = futures_util::future::join(stream.next(), post).await;
+    posted?;
+    assert_eq!(entry.unwrap()?.message, "syncthing-rs new error");
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore]
 async fn get_system_upgrade() -> Fallible<()> {
@@ -60,6 +624,89 @@ async fn get_system_version() -> Fallible<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_metrics() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_metrics().await?;
+    Ok(())
+}
+
+#[test]
+fn parse_metrics() -> Fallible<()> {
+    let metrics: Metrics = r#"
+# HELP syncthing_protocol_sent_bytes_total Total amount of data sent, per device
+# TYPE syncthing_protocol_sent_bytes_total counter
+syncthing_protocol_sent_bytes_total{device="ABCDEFG"} 1024
+syncthing_protocol_sent_bytes_total{device="HIJKLMN"} 2048
+# TYPE syncthing_model_folder_state gauge
+syncthing_model_folder_state{folder="default"} 0
+# TYPE go_gc_duration_seconds summary
+go_gc_duration_seconds{quantile="0.5"} 2.5e-05
+go_gc_duration_seconds_sum 0.5
+go_gc_duration_seconds_count 12
+# TYPE http_request_duration_seconds histogram
+http_request_duration_seconds_bucket{path="/rest/a\"b",le="0.1"} 3
+http_request_duration_seconds_bucket{path="/rest/a\"b",le="+Inf"} 5 1600000000000
+http_request_duration_seconds_sum{path="/rest/a\"b"} 0.7
+http_request_duration_seconds_count{path="/rest/a\"b"} 5
+process_start_time_seconds 1.6e+09
+"#
+    .parse()?;
+    assert_eq!(metrics.families.len(), 5);
+    assert_eq!(metrics.sent_bytes("ABCDEFG"), Some(1024.0));
+    assert_eq!(
+        metrics.value("syncthing_protocol_sent_bytes_total", &[]),
+        Some(3072.0)
+    );
+    assert_eq!(metrics.folder_state("default"), Some(0.0));
+    assert_eq!(metrics.folder_state("missing"), None);
+    let histogram = metrics.family("http_request_duration_seconds").unwrap();
+    assert_eq!(histogram.metrics.len(), 1);
+    assert_eq!(histogram.metrics[0].labels["path"], "/rest/a\"b");
+    match &histogram.metrics[0].value {
+        Value::Histogram {
+            buckets,
+            sum,
+            count,
+        } => {
+            assert_eq!(buckets.len(), 2);
+            assert_eq!(buckets[1].upper_bound, f64::INFINITY);
+            assert_eq!((*sum, *count), (0.7, 5.0));
+        }
+        value => panic!("unexpected value {:?}", value),
+    }
+    let summary = metrics.family("go_gc_duration_seconds").unwrap();
+    assert_eq!(summary.metric_type, MetricType::Summary);
+    assert_eq!(
+        metrics.value("process_start_time_seconds", &[]),
+        Some(1.6e9)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn health() -> Fallible<()> {
+    let client = Client::new_unauthenticated();
+    client.health().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn readiness() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    assert!(!matches!(
+        client.readiness().await,
+        Readiness::Unhealthy(_) | Readiness::Unavailable(_)
+    ));
+    let client = Client::new_unauthenticated();
+    client.health().await?;
+    assert!(matches!(
+        client.readiness().await,
+        Readiness::Unavailable(_)
+    ));
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_events() -> Fallible<()> {
     let client = Client::new(API_KEY);
@@ -67,6 +714,60 @@ async fn get_events() -> Fallible<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_disk_events() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    client.get_disk_events(None, Some(1)).await?;
+    Ok(())
+}
+
+#[test]
+fn parse_unknown_events() -> Fallible<()> {
+    let events: Vec<Event> = serde_json::from_str(
+        r#"[
+            {
+                "id": 1,
+                "globalID": 1,
+                "type": "PendingDevicesChanged",
+                "time": "2020-06-04T15:47:52+02:00",
+                "data": {"added": [{"deviceID": "ABCDEFG", "name": "", "address": ""}]}
+            },
+            {
+                "id": 2,
+                "globalID": 2,
+                "type": "StateChanged",
+                "time": "2020-06-04T15:47:53+02:00",
+                "data": {"folder": "default", "from": "scanning", "to": "idle", "duration": 0.5}
+            }
+        ]"#,
+    )?;
+    assert!(matches!(
+        &events[0].data,
+        EventData::Unknown(event_type) if event_type == "PendingDevicesChanged"
+    ));
+    assert!(matches!(&events[1].data, EventData::StateChanged(_)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn disk_event_stream() -> Fallible<()> {
+    let client = Client::new(API_KEY);
+    let mut stream = client.subscribe_to_disk_events();
+    let mut last: Option<u64> = None;
+    // disk events are rare, so only check the ones that arrive in time
+    for _ in 0..4 {
+        let event = match tokio::time::timeout(Duration::from_secs(10), stream.next()).await {
+            Ok(Some(event)) => event?,
+            Ok(None) | Err(_) => break,
+        };
+        if let Some(last) = last {
+            assert_eq!(last + 1, event.id);
+        }
+        last = Some(event.id);
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn event_stream() -> Fallible<()> {
     let client = Client::new(API_KEY);
@@ -0,0 +1,122 @@
+use crate::rest::db::browse::{Browse, Entry};
+use crate::rest::db::FileType;
+use crate::{Client, Fallible};
+use futures_core::future::BoxFuture;
+use futures_core::ready;
+use futures_core::stream::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type Directory = (String, usize);
+
+async fn receive(
+    client: Client,
+    folder: String,
+    directory: Directory,
+) -> (Client, Directory, Fallible<Browse>) {
+    let data = client.browse_db(&folder, Some(&directory.0), Some(0)).await;
+    (client, directory, data)
+}
+
+#[derive(Debug)]
+pub struct TreeEntry {
+    /// Path of the entry relative to the folder root.
+    pub path: String,
+    /// Number of directories between the walked prefix and the entry.
+    pub depth: usize,
+    pub entry: Entry,
+}
+
+enum State {
+    Buffer(Option<Client>, Vec<TreeEntry>),
+    Future(BoxFuture<'static, (Client, Directory, Fallible<Browse>)>),
+}
+
+/// Lazily walks the global file tree of a folder, fetching one directory at a time.
+pub struct TreeStream {
+    state: State,
+    folder: String,
+    max_depth: Option<usize>,
+    pending: Vec<Directory>,
+}
+
+impl TreeStream {
+    pub(crate) fn new(
+        client: Client,
+        folder: String,
+        prefix: String,
+        max_depth: Option<usize>,
+    ) -> Self {
+        Self {
+            state: State::Future(Box::pin(receive(client, folder.clone(), (prefix, 0)))),
+            folder,
+            max_depth,
+            pending: Vec::new(),
+        }
+    }
+
+    fn tree_entries(&mut self, (prefix, depth): Directory, browse: Browse) -> Vec<TreeEntry> {
+        let descend = match self.max_depth {
+            Some(max_depth) => depth < max_depth,
+            None => true,
+        };
+        browse
+            .into_iter()
+            .map(|entry| {
+                let path = if prefix.is_empty() {
+                    entry.name.clone()
+                } else {
+                    format!("{}/{}", prefix.trim_end_matches('/'), entry.name)
+                };
+                if descend && entry.entry_type == FileType::Directory {
+                    self.pending.push((path.clone(), depth + 1));
+                }
+                TreeEntry { path, depth, entry }
+            })
+            .collect()
+    }
+
+    fn next_directory(&mut self, client: Client) -> Option<State> {
+        let directory = self.pending.pop()?;
+        Some(State::Future(Box::pin(receive(
+            client,
+            self.folder.clone(),
+            directory,
+        ))))
+    }
+}
+
+impl Stream for TreeStream {
+    type Item = Fallible<TreeEntry>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match &mut self.state {
+                State::Future(fut) => match ready!(fut.as_mut().poll(cx)) {
+                    (client, directory, Ok(browse)) => {
+                        let mut entries = self.tree_entries(directory, browse);
+                        entries.reverse();
+                        self.state = State::Buffer(Some(client), entries);
+                    }
+                    (client, _, Err(err)) => {
+                        self.state = State::Buffer(Some(client), Vec::new());
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+                State::Buffer(client, entries) => {
+                    if let Some(entry) = entries.pop() {
+                        return Poll::Ready(Some(Ok(entry)));
+                    }
+                    let client = match client.take() {
+                        Some(client) => client,
+                        None => return Poll::Ready(None),
+                    };
+                    match self.next_directory(client) {
+                        Some(state) => self.state = state,
+                        None => return Poll::Ready(None),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -13,6 +13,40 @@ impl QueryChars {
             '?'
         }
     }
+
+    pub fn push_param(&mut self, path_and_query: &mut String, key: &str, value: &str) {
+        path_and_query.push(self.next_char());
+        path_and_query.push_str(key);
+        path_and_query.push('=');
+        path_and_query.push_str(&percent_encode(value));
+    }
+
+    pub fn push_page_params(
+        &mut self,
+        path_and_query: &mut String,
+        page: Option<u64>,
+        perpage: Option<u64>,
+    ) {
+        if let Some(page) = page {
+            self.push_param(path_and_query, "page", &page.to_string());
+        }
+        if let Some(perpage) = perpage {
+            self.push_param(path_and_query, "perpage", &perpage.to_string());
+        }
+    }
+}
+
+pub fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
 }
 
 use serde::{Deserialize, Deserializer};